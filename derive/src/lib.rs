@@ -0,0 +1,406 @@
+// operator-sugar
+// Copyright (C) SOFe
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Derive macros for newtypes with a single field, implementing operators that delegate to the
+//! field. This is the companion of [operator-sugar](https://docs.rs/operator-sugar) for the
+//! trivial cases; use `operator!` for operators with custom bodies.
+//!
+//! Binary operators take `Self` as the right operand and return `Self`:
+//!
+//! ```
+//! use operator_sugar_derive::{Add, Neg, Sub};
+//!
+//! #[derive(Debug, PartialEq, Add, Sub, Neg)]
+//! struct Meters(f64);
+//!
+//! fn main() {
+//!     assert_eq!(Meters(1.0) + Meters(2.0) - Meters(0.5), Meters(2.5));
+//!     assert_eq!(-Meters(1.0), Meters(-1.0));
+//! }
+//! ```
+//!
+//! Named fields and generics are supported as well. The field type is required to implement the
+//! operator with itself as the output:
+//!
+//! ```
+//! use operator_sugar_derive::{BitOr, Mul, Not};
+//!
+//! #[derive(Debug, PartialEq, Mul)]
+//! struct Scaled<T> {
+//!     value: T,
+//! }
+//!
+//! #[derive(Debug, PartialEq, BitOr, Not)]
+//! struct Flags(u8);
+//!
+//! fn main() {
+//!     assert_eq!(Scaled { value: 3 } * Scaled { value: 4 }, Scaled { value: 12 });
+//!     assert_eq!(!(Flags(1) | Flags(4)), Flags(!5));
+//! }
+//! ```
+//!
+//! Only structs with exactly one field are accepted:
+//!
+//! ```compile_fail
+//! use operator_sugar_derive::Add;
+//!
+//! // error: `Add` can only be derived for structs with a single field
+//! #[derive(Add)]
+//! struct Point(i32, i32);
+//! ```
+
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+macro_rules! derive_binary {
+    ($($fn_name:ident $Trait:ident $method:ident;)*) => {
+        $(
+            #[doc = concat!("Implements `core::ops::", stringify!($Trait), "` by delegating to the field.")]
+            #[proc_macro_derive($Trait)]
+            pub fn $fn_name(input: TokenStream) -> TokenStream {
+                expand(input, stringify!($Trait), |newtype| {
+                    newtype.binary(stringify!($Trait), stringify!($method))
+                })
+            }
+        )*
+    };
+}
+
+macro_rules! derive_unary {
+    ($($fn_name:ident $Trait:ident $method:ident;)*) => {
+        $(
+            #[doc = concat!("Implements `core::ops::", stringify!($Trait), "` by delegating to the field.")]
+            #[proc_macro_derive($Trait)]
+            pub fn $fn_name(input: TokenStream) -> TokenStream {
+                expand(input, stringify!($Trait), |newtype| {
+                    newtype.unary(stringify!($Trait), stringify!($method))
+                })
+            }
+        )*
+    };
+}
+
+derive_binary! {
+    derive_add Add add;
+    derive_sub Sub sub;
+    derive_mul Mul mul;
+    derive_div Div div;
+    derive_rem Rem rem;
+    derive_bitand BitAnd bitand;
+    derive_bitor BitOr bitor;
+    derive_bitxor BitXor bitxor;
+}
+
+derive_unary! {
+    derive_neg Neg neg;
+    derive_not Not not;
+}
+
+fn expand(input: TokenStream, name: &str, f: impl FnOnce(&Newtype) -> String) -> TokenStream {
+    let code = match Newtype::parse(input) {
+        Ok(newtype) => f(&newtype),
+        Err(message) => return compile_error(&message.replace("{}", name)),
+    };
+    match code.parse() {
+        Ok(tokens) => tokens,
+        Err(_) => compile_error(&format!("failed to derive `{}` for this struct", name)),
+    }
+}
+
+/// Builds `compile_error!("message");` without going through the parser.
+fn compile_error(message: &str) -> TokenStream {
+    let tokens = vec![
+        TokenTree::Ident(Ident::new("compile_error", Span::call_site())),
+        TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+        TokenTree::Group(Group::new(
+            Delimiter::Parenthesis,
+            TokenTree::Literal(Literal::string(message)).into(),
+        )),
+        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+    ];
+    tokens.into_iter().collect()
+}
+
+/// A struct with a single field, as far as the derives need to know about it.
+struct Newtype {
+    name: String,
+    impl_generics: String,
+    ty_generics: String,
+    predicates: Vec<String>,
+    /// The field name, or `None` for tuple structs.
+    field: Option<String>,
+    field_ty: String,
+}
+
+impl Newtype {
+    fn parse(input: TokenStream) -> Result<Self, String> {
+        let tokens: Vec<TokenTree> = input.into_iter().collect();
+        let mut i = 0;
+
+        loop {
+            match tokens.get(i) {
+                Some(TokenTree::Punct(p)) if p.as_char() == '#' => match tokens.get(i + 1) {
+                    Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket => i += 2,
+                    _ => return Err("expected attribute".into()),
+                },
+                Some(TokenTree::Ident(ident)) if ident.to_string() == "pub" => {
+                    i += 1;
+                    if let Some(TokenTree::Group(group)) = tokens.get(i) {
+                        if group.delimiter() == Delimiter::Parenthesis {
+                            i += 1;
+                        }
+                    }
+                }
+                _ => break,
+            }
+        }
+
+        match tokens.get(i) {
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "struct" => i += 1,
+            _ => return Err("`{}` can only be derived for structs".into()),
+        }
+        let name = match tokens.get(i) {
+            Some(TokenTree::Ident(ident)) => ident.to_string(),
+            _ => return Err("expected struct name".into()),
+        };
+        i += 1;
+
+        let mut params = Vec::new();
+        if is_punct(tokens.get(i), '<') {
+            let end = closing_angle(&tokens, i).ok_or("unclosed `<` in generics")?;
+            params = split_top_level(&tokens[i + 1..end], ',');
+            i = end + 1;
+        }
+
+        let mut predicates = Vec::new();
+        let mut body = None;
+        while let Some(token) = tokens.get(i) {
+            match token {
+                TokenTree::Ident(ident) if ident.to_string() == "where" => {
+                    let end = tokens[i..]
+                        .iter()
+                        .position(|token| match token {
+                            TokenTree::Group(group) => group.delimiter() == Delimiter::Brace,
+                            TokenTree::Punct(p) => p.as_char() == ';',
+                            _ => false,
+                        })
+                        .map_or(tokens.len(), |offset| i + offset);
+                    predicates = split_top_level(&tokens[i + 1..end], ',')
+                        .into_iter()
+                        .map(to_string)
+                        .collect();
+                    i = end;
+                }
+                TokenTree::Group(group) if body.is_none() => {
+                    body = Some(group.clone());
+                    i += 1;
+                }
+                _ => i += 1,
+            }
+        }
+        let body = body.ok_or("`{}` can only be derived for structs with a single field")?;
+
+        let fields: Vec<TokenTree> = body.stream().into_iter().collect();
+        let fields = split_top_level(&fields, ',');
+        let field = match fields.as_slice() {
+            [field] => *field,
+            _ => return Err("`{}` can only be derived for structs with a single field".into()),
+        };
+        let field = skip_attrs_and_vis(field);
+        let (field, field_ty) = if body.delimiter() == Delimiter::Brace {
+            match field {
+                [TokenTree::Ident(ident), colon, ty @ ..] if is_punct(Some(colon), ':') => {
+                    (Some(ident.to_string()), to_string(ty))
+                }
+                _ => return Err("expected field name".into()),
+            }
+        } else {
+            (None, to_string(field))
+        };
+
+        let impl_generics = params
+            .iter()
+            .map(|param| {
+                // strip the default value
+                to_string(split_top_level(param, '=').first().copied().unwrap_or_default())
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let ty_generics = params
+            .iter()
+            .map(|param| match param {
+                [TokenTree::Punct(p), lifetime, ..] if p.as_char() == '\'' => format!("'{}", lifetime),
+                [TokenTree::Ident(ident), name, ..] if ident.to_string() == "const" => name.to_string(),
+                [name, ..] => name.to_string(),
+                [] => String::new(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Ok(Newtype {
+            name,
+            impl_generics,
+            ty_generics,
+            predicates,
+            field,
+            field_ty,
+        })
+    }
+
+    fn header(&self, trait_name: &str) -> String {
+        let mut predicates = self.predicates.clone();
+        predicates.push(format!(
+            "{ty}: ::core::ops::{tr}<Output = {ty}>",
+            ty = self.field_ty,
+            tr = trait_name,
+        ));
+        format!(
+            "impl<{}> ::core::ops::{} for {}<{}> where {}",
+            self.impl_generics,
+            trait_name,
+            self.name,
+            self.ty_generics,
+            predicates.join(", "),
+        )
+    }
+
+    fn construct(&self, value: String) -> String {
+        match &self.field {
+            Some(field) => format!("Self {{ {}: {} }}", field, value),
+            None => format!("Self({})", value),
+        }
+    }
+
+    fn access(&self) -> &str {
+        self.field.as_deref().unwrap_or("0")
+    }
+
+    fn binary(&self, trait_name: &str, method: &str) -> String {
+        format!(
+            "{header} {{
+                type Output = Self;
+
+                #[inline]
+                fn {method}(self, rhs: Self) -> Self {{
+                    {body}
+                }}
+            }}",
+            header = self.header(trait_name),
+            method = method,
+            body = self.construct(format!(
+                "::core::ops::{}::{}(self.{field}, rhs.{field})",
+                trait_name,
+                method,
+                field = self.access(),
+            )),
+        )
+    }
+
+    fn unary(&self, trait_name: &str, method: &str) -> String {
+        format!(
+            "{header} {{
+                type Output = Self;
+
+                #[inline]
+                fn {method}(self) -> Self {{
+                    {body}
+                }}
+            }}",
+            header = self.header(trait_name),
+            method = method,
+            body = self.construct(format!(
+                "::core::ops::{}::{}(self.{})",
+                trait_name,
+                method,
+                self.access(),
+            )),
+        )
+    }
+}
+
+fn is_punct(token: Option<&TokenTree>, ch: char) -> bool {
+    match token {
+        Some(TokenTree::Punct(p)) => p.as_char() == ch,
+        _ => false,
+    }
+}
+
+/// Returns the index of the `>` matching the `<` at `start`, or `None` if it is not closed.
+fn closing_angle(tokens: &[TokenTree], start: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut arrow = false;
+    for (i, token) in tokens.iter().enumerate().skip(start) {
+        if let TokenTree::Punct(p) = token {
+            match p.as_char() {
+                '<' => depth += 1,
+                '>' if !arrow => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(i);
+                    }
+                }
+                _ => {}
+            }
+            arrow = p.as_char() == '-' && p.spacing() == Spacing::Joint;
+        } else {
+            arrow = false;
+        }
+    }
+    None
+}
+
+/// Splits at the `separator`s outside of `<>`, dropping a trailing empty part.
+fn split_top_level(tokens: &[TokenTree], separator: char) -> Vec<&[TokenTree]> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        if let TokenTree::Punct(p) = token {
+            match p.as_char() {
+                '<' => depth += 1,
+                '>' if depth > 0 => depth -= 1,
+                ch if ch == separator && depth == 0 => {
+                    parts.push(&tokens[start..i]);
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+    }
+    if start < tokens.len() {
+        parts.push(&tokens[start..]);
+    }
+    parts
+}
+
+fn skip_attrs_and_vis(mut tokens: &[TokenTree]) -> &[TokenTree] {
+    loop {
+        match tokens {
+            [TokenTree::Punct(p), TokenTree::Group(_), rest @ ..] if p.as_char() == '#' => tokens = rest,
+            [TokenTree::Ident(ident), TokenTree::Group(group), rest @ ..]
+                if ident.to_string() == "pub" && group.delimiter() == Delimiter::Parenthesis =>
+            {
+                tokens = rest
+            }
+            [TokenTree::Ident(ident), rest @ ..] if ident.to_string() == "pub" => tokens = rest,
+            _ => return tokens,
+        }
+    }
+}
+
+fn to_string(tokens: &[TokenTree]) -> String {
+    tokens.iter().cloned().collect::<TokenStream>().to_string()
+}
@@ -0,0 +1,8 @@
+use operator_sugar_derive::Add;
+
+#[derive(Add)]
+enum Meters {
+    Value(f64),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use operator_sugar_derive::Mul;
+
+#[derive(Mul)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use operator_sugar_derive::Neg;
+
+#[derive(Neg)]
+struct Unit;
+
+fn main() {}
@@ -30,492 +30,4166 @@
 //! });
 //! ```
 //!
-//! For unary operators:
+//! If the right operand has the same type as the left operand, the second type can be omitted:
 //!
 //! ```
 //! # use operator_sugar::*;
-//! struct Operand(i32);
-//! struct Answer(i32);
+//! #[derive(Debug, PartialEq)] struct Complex(f64, f64);
 //!
-//! operator!(Operand: -a -> Answer {
-//!     Answer(-a.0)
+//! operator!(Complex: a + b -> Complex {
+//!     Complex(a.0 + b.0, a.1 + b.1)
 //! });
+//!
+//! fn main() {
+//!     assert_eq!(Complex(1.0, 2.0) + Complex(3.0, 4.0), Complex(4.0, 6.0));
+//! }
 //! ```
 //!
-//! # Meta Attributes
-//! Attributes can be applied to the `impl` block (which implements e.g. `Add`) and the `fn` block respectively:
+//! If the output also has the same type, `-> Output` can be omitted as well. The body still
+//! evaluates to the output value itself:
 //!
 //! ```
 //! # use operator_sugar::*;
-//! struct Left(i32);
-//! struct Right(i32);
-//! struct Answer(i32);
+//! #[derive(Debug, PartialEq)] struct Money(i64);
 //!
-//! operator!(
-//!     #[doc("This attribute will be applied on the `impl` block")] Left, Right:
-//!     #[doc("This attribute will be applied on the `fn` block")] a + b -> Answer {
-//!         Answer(a.0 + b.0)
-//!     });
-//! ```
+//! operator!(Money: a + b {
+//!     Money(a.0 + b.0)
+//! });
+//! operator!(Money: a - b => Money(a.0 - b.0));
 //!
-//! # Generics
-//! Generics can be used on the three types and on the `impl` block.
+//! fn main() {
+//!     assert_eq!(Money(1) + Money(2), Money(3));
+//!     assert_eq!(Money(3) - Money(2), Money(1));
+//! }
+//! ```
 //!
-//! Due to disambiguation, generic parameters for the `impl` block must be written in `{}` rather
-//! than `<>`.
+//! When two types are given, the output type is still required:
 //!
-//! ```
+//! ```compile_fail
 //! # use operator_sugar::*;
-//! use core::ops::Add;
-//! struct Left<T>(T) where T: Add<i32, Output = i32>;
-//! struct Right(i32);
-//! struct Answer(i32);
+//! struct Money(i64);
 //!
-//! operator!(
-//!     {T: Add<i32, Output = i32>}
-//!     Left<T>, Right: a + b -> Answer {
-//!         Answer(a.0 + b.0)
-//!     });
+//! // error: The `*` operator requires an output type, as in `a * b -> Output`
+//! operator!(Money, i64: a * b {
+//!     Money(a.0 * b)
+//! });
 //! ```
 //!
-//! # List of operators
-//! For conciseness, these definitions are defined for each of the following examples:
-//! ```no_run
-//! use operator_sugar::*;
-//! #[derive(Debug)] struct Left(i32);
-//! #[derive(Debug)] struct Right(i32);
-//! #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
-//! ```
+//! The body is placed in a method of the generated `impl` block, so `Self` refers to the left
+//! operand type and its associated items can be used. The receiver is bound to the left operand
+//! name instead of `self`: it is moved into that name for operators that take `self` by value, and
+//! it is a reference for operators that take `&self` or `&mut self`.
 //!
-//! ## Addition
 //! ```
 //! # use operator_sugar::*;
-//! # #[derive(Debug)] struct Left(i32);
-//! # #[derive(Debug)] struct Right(i32);
-//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
-//! #
-//! operator!(Left, Right: a + b -> Answer {
-//!     Answer(a.0 + b.0)
+//! #[derive(Debug, PartialEq)] struct Millimeters(i64);
+//!
+//! impl Millimeters {
+//!     const PER_METER: i64 = 1000;
+//!
+//!     fn value(&self) -> i64 {
+//!         self.0
+//!     }
+//! }
+//!
+//! operator!(Millimeters, i64: a + meters -> Millimeters {
+//!     Millimeters(a.value() + meters * Self::PER_METER)
 //! });
 //!
 //! fn main() {
-//!     assert_eq!(Left(1) + Right(2), Answer(3));
+//!     assert_eq!(Millimeters(5) + 2, Millimeters(2005));
 //! }
 //! ```
 //!
-//! ## Subtraction
+//! For unary operators:
+//!
 //! ```
 //! # use operator_sugar::*;
-//! # #[derive(Debug)] struct Left(i32);
-//! # #[derive(Debug)] struct Right(i32);
-//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
-//! #
-//! operator!(Left, Right: a - b -> Answer {
-//!     Answer(a.0 - b.0)
-//! });
+//! struct Operand(i32);
+//! struct Answer(i32);
 //!
-//! fn main() {
-//!     assert_eq!(Left(1) - Right(2), Answer(-1));
-//! }
+//! operator!(Operand: -a -> Answer {
+//!     Answer(-a.0)
+//! });
 //! ```
 //!
-//! ## Multiplication
+//! A body that consists of a single expression can be written after `=>` instead of in braces:
+//!
 //! ```
 //! # use operator_sugar::*;
-//! # #[derive(Debug)] struct Left(i32);
-//! # #[derive(Debug)] struct Right(i32);
-//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
-//! #
-//! operator!(Left, Right: a * b -> Answer {
-//!     Answer(a.0 * b.0)
-//! });
+//! struct Left(i32);
+//! struct Right(i32);
+//! #[derive(Debug, PartialEq)] struct Answer(i32);
+//!
+//! operator!(Left, Right: a + b -> Answer => Answer(a.0 + b.0));
+//! operator!(Answer: -a -> Answer => Answer(-a.0));
 //!
 //! fn main() {
-//!     assert_eq!(Left(3) * Right(2), Answer(6));
+//!     assert_eq!(-(Left(1) + Right(2)), Answer(-3));
 //! }
 //! ```
 //!
-//! ## Division
+//! The left operand can also be named `self`, in which case it is used as the receiver directly.
+//! This allows the body of an existing `impl` block to be reused verbatim:
+//!
 //! ```
 //! # use operator_sugar::*;
-//! # #[derive(Debug)] struct Left(i32);
-//! # #[derive(Debug)] struct Right(i32);
-//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
-//! #
-//! operator!(Left, Right: a / b -> Answer {
-//!     Answer(a.0 / b.0)
+//! #[derive(Debug)] struct Complex { re: f64, im: f64 }
+//!
+//! operator!(Complex: self * rhs -> Complex {
+//!     Complex {
+//!         re: self.re * rhs.re - self.im * rhs.im,
+//!         im: self.re * rhs.im + self.im * rhs.re,
+//!     }
+//! });
+//!
+//! operator!(Complex: self == other {
+//!     self.re == other.re && self.im == other.im
 //! });
 //!
 //! fn main() {
-//!     assert_eq!(Left(8) / Right(2), Answer(4));
+//!     let i = Complex { re: 0.0, im: 1.0 };
+//!     assert_eq!(i * Complex { re: 0.0, im: 1.0 }, Complex { re: -1.0, im: 0.0 });
 //! }
 //! ```
 //!
-//! ## Remainder
-//! ```
+//! This is not supported with the `commutative`, `swap` and `by_key` prefixes, which also use the
+//! body in a method where the left operand is not the receiver:
+//!
+//! ```compile_fail
 //! # use operator_sugar::*;
-//! # #[derive(Debug)] struct Left(i32);
-//! # #[derive(Debug)] struct Right(i32);
-//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
-//! #
-//! operator!(Left, Right: a % b -> Answer {
-//!     Answer(a.0 % b.0)
-//! });
+//! struct Meters(f64);
 //!
-//! fn main() {
-//!     assert_eq!(Left(9) % Right(5), Answer(4));
-//! }
+//! // error: The left operand cannot be named `self` with `commutative`, ...
+//! operator!(commutative Meters, f64: self * k -> Meters {
+//!     Meters(self.0 * k)
+//! });
 //! ```
 //!
-//! ## Bitwise AND
+//! An operand can be destructured with a tuple struct or struct pattern in place of its name:
+//!
 //! ```
 //! # use operator_sugar::*;
-//! # #[derive(Debug)] struct Left(i32);
-//! # #[derive(Debug)] struct Right(i32);
-//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
-//! #
-//! operator!(Left, Right: a & b -> Answer {
-//!     Answer(a.0 & b.0)
+//! #[derive(Debug, PartialEq)] struct Point(i32, i32);
+//!
+//! operator!(Point: Point(x1, y1) + Point(x2, y2) -> Point {
+//!     Point(x1 + x2, y1 + y2)
+//! });
+//!
+//! operator!(Point, i32: Point(x, y) * k -> Point {
+//!     Point(x * k, y * k)
+//! });
+//!
+//! operator!(Point: -Point(x, y) -> Point {
+//!     Point(-x, -y)
+//! });
+//!
+//! #[derive(Debug, PartialEq)] struct Polar { r: f64, theta: f64 }
+//!
+//! operator!(Polar: Polar { r: r1, theta: t1 } * Polar { r: r2, theta: t2 } -> Polar {
+//!     Polar { r: r1 * r2, theta: t1 + t2 }
+//! });
+//!
+//! operator!(Polar, f64: Polar { r, theta } / k -> Polar {
+//!     Polar { r: r / k, theta }
 //! });
 //!
 //! fn main() {
-//!     assert_eq!(Left(5) & Right(6), Answer(4));
+//!     assert_eq!(-(Point(1, 2) + Point(3, 4)) * 2, Point(-8, -12));
+//!     let z = Polar { r: 2.0, theta: 0.5 } * Polar { r: 3.0, theta: 0.25 } / 2.0;
+//!     assert_eq!(z, Polar { r: 3.0, theta: 0.75 });
 //! }
 //! ```
 //!
-//! ## Bitwise OR
+//! Other patterns, such as reference and tuple patterns, are not accepted; bind the operand to a
+//! name and destructure it in the body instead.
+//!
+//! A trailing comma after the body is accepted in every form:
+//!
 //! ```
 //! # use operator_sugar::*;
-//! # #[derive(Debug)] struct Left(i32);
-//! # #[derive(Debug)] struct Right(i32);
-//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
-//! #
-//! operator!(Left, Right: a | b -> Answer {
-//!     Answer(a.0 | b.0)
-//! });
+//! struct Operand(i32);
+//! #[derive(Debug)] struct Answer(i32);
+//!
+//! operator!(Operand, Operand: a + b -> Answer {
+//!     Answer(a.0 + b.0)
+//! },);
+//!
+//! operator!(Answer: a += b {
+//!     a.0 += b.0;
+//! },);
+//!
+//! operator!(Answer: a == b => a.0 == b.0,);
 //!
 //! fn main() {
-//!     assert_eq!(Left(5) | Right(6), Answer(7));
+//!     let mut answer = Operand(1) + Operand(2);
+//!     answer += Answer(3);
+//!     assert_eq!(answer, Answer(6));
 //! }
 //! ```
 //!
-//! ## Bitwise XOR
 //! ```
 //! # use operator_sugar::*;
-//! # #[derive(Debug)] struct Left(i32);
-//! # #[derive(Debug)] struct Right(i32);
-//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
-//! #
-//! operator!(Left, Right: a ^ b -> Answer {
-//!     Answer(a.0 ^ b.0)
-//! });
+//! struct List(Vec<i32>);
+//!
+//! operator!(List, usize: a[b] -> &i32 {
+//!     &a.0[b]
+//! },);
+//!
+//! operator!(List, u32: a[b] -> &i32 {
+//!     &a.0[b as usize]
+//! } mut {
+//!     &mut a.0[b as usize]
+//! },);
+//!
+//! operator!(List: *a -> &[i32] {
+//!     &a.0
+//! },);
 //!
 //! fn main() {
-//!     assert_eq!(Left(5) ^ Right(6), Answer(3));
+//!     let mut list = List(vec![1, 2, 3]);
+//!     list[1u32] += 10;
+//!     assert_eq!(list[1usize], 12);
+//!     assert_eq!(list.len(), 3);
 //! }
 //! ```
 //!
-//! ## Shift-left
+//! # Meta Attributes
+//! Attributes can be applied to the `impl` block (which implements e.g. `Add`) and the `fn` block respectively:
+//!
 //! ```
 //! # use operator_sugar::*;
-//! # #[derive(Debug)] struct Left(i32);
-//! # #[derive(Debug)] struct Right(i32);
-//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
-//! #
-//! operator!(Left, Right: a << b -> Answer {
-//!     Answer(a.0 << b.0)
-//! });
+//! struct Left(i32);
+//! struct Right(i32);
+//! struct Answer(i32);
 //!
-//! fn main() {
-//!     assert_eq!(Left(5) << Right(3), Answer(40));
-//! }
+//! operator!(
+//!     #[doc = "This attribute will be applied on the `impl` block"] Left, Right:
+//!     #[doc = "This attribute will be applied on the `fn` block"] a + b -> Answer {
+//!         Answer(a.0 + b.0)
+//!     });
 //! ```
 //!
-//! ## Shift-right
+//! Any number of attributes can be stacked in each position:
+//!
 //! ```
 //! # use operator_sugar::*;
-//! # #[derive(Debug)] struct Left(i32);
-//! # #[derive(Debug)] struct Right(i32);
-//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
-//! #
-//! operator!(Left, Right: a >> b -> Answer {
-//!     Answer(a.0 >> b.0)
-//! });
+//! struct Left(i32);
+//! struct Right(i32);
+//! #[derive(Debug, PartialEq)] struct Answer(i32);
+//!
+//! operator!(
+//!     #[allow(clippy::suspicious_arithmetic_impl)]
+//!     #[cfg(not(target_pointer_width = "16"))]
+//!     #[doc = "Subtracts by adding the negation"]
+//!     Left, Right:
+//!     #[inline]
+//!     #[cfg(all())]
+//!     #[allow(unused_variables)]
+//!     a - b -> Answer {
+//!         let unused = 0;
+//!         Answer(a.0 + -b.0)
+//!     });
+//!
+//! operator!(
+//!     inline
+//!     #[allow(clippy::suspicious_op_assign_impl)]
+//!     #[cfg(not(target_pointer_width = "16"))]
+//!     Answer, Right:
+//!     #[cfg(all())]
+//!     #[allow(unused_variables)]
+//!     a -= b {
+//!         let unused = 0;
+//!         a.0 += -b.0;
+//!     });
 //!
 //! fn main() {
-//!     assert_eq!(Left(43) >> Right(3), Answer(5));
+//!     let mut answer = Left(3) - Right(1);
+//!     answer -= Right(1);
+//!     assert_eq!(answer, Answer(1));
 //! }
 //! ```
 //!
-//! ## Index
+//! A `#[cfg]` attribute in the `impl` position gates the whole implementation:
+//!
 //! ```
 //! # use operator_sugar::*;
-//! #[derive(Debug)] struct Left(Vec<i32>);
-//! #[derive(Debug)] struct Right(usize);
+//! struct Left(i32);
+//! struct Right(i32);
+//! #[derive(Debug, PartialEq)] struct Answer(i32);
 //!
-//! operator!(Left, Right: a[b] -> &i32 {
-//!     // The & is required to remind developers that a reference is to be returned.
-//!     &a.0[b.0]
-//! });
+//! operator!(
+//!     #[cfg(target_pointer_width = "16")]
+//!     Left, Right: a + b -> Answer {
+//!         Answer(a.0 + b.0 + 16)
+//!     });
+//!
+//! operator!(
+//!     #[cfg(not(target_pointer_width = "16"))]
+//!     Left, Right: a + b -> Answer {
+//!         Answer(a.0 + b.0)
+//!     });
 //!
 //! fn main() {
-//!     // We check for 6 not &6, because while the impl returns &6, the [] operator from Rust dereferences it.
-//!     assert_eq!(Left(vec![5, 6, 7])[Right(1)], 6);
+//!     assert_eq!(Left(1) + Right(2), Answer(3));
 //! }
 //! ```
 //!
-//! ## Negative (`-`)
+//! Doc comments are attributes too, so they can be written in either position with `///`:
+//!
 //! ```
 //! # use operator_sugar::*;
-//! # #[derive(Debug)] struct Left(i32);
-//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
+//! struct Left(i32);
+//! struct Right(i32);
+//! #[derive(Debug, PartialEq)] struct Answer(i32);
 //!
-//! operator!(Left: -a -> Answer {
-//!     Answer(-a.0)
-//! });
+//! operator!(
+//!     /// Adds the values of the two operands.
+//!     Left, Right:
+//!     /// Returns the sum of `a` and `b`.
+//!     #[inline]
+//!     a + b -> Answer {
+//!         Answer(a.0 + b.0)
+//!     });
+//!
+//! operator!(
+//!     /// Negates the operand.
+//!     Answer:
+//!     /// Returns the negated value.
+//!     -a -> Answer {
+//!         Answer(-a.0)
+//!     });
 //!
 //! fn main() {
-//!     assert_eq!(-Left(43), Answer(-43));
+//!     assert_eq!(-(Left(1) + Right(2)), Answer(-3));
 //! }
 //! ```
 //!
-//! ## Not (`!`)
+//! For unary operators, the `fn` attributes go before the operator symbol:
+//!
 //! ```
 //! # use operator_sugar::*;
-//! # #[derive(Debug)] struct Left(i32);
-//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
+//! struct Predicate(bool);
+//! struct InvertedPredicate(bool);
 //!
-//! operator!(Left: !a -> Answer {
-//!     Answer(!a.0)
+//! operator!(
+//!     #[doc = "This attribute will be applied on the `impl` block"] Predicate:
+//!     #[inline] !a -> InvertedPredicate {
+//!         InvertedPredicate(!a.0)
+//!     });
+//! ```
+//!
+//! Prefix the invocation with `inline` to add `#[inline]` to the generated method. It must come
+//! before the other keywords and can be combined with them and with attributes:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, Copy, PartialEq)] struct Vec2(i32, i32);
+//!
+//! operator!(inline Vec2: a + b -> Vec2 {
+//!     Vec2(a.0 + b.0, a.1 + b.1)
+//! });
+//!
+//! operator!(inline ref Vec2, i32: #[must_use] a * b -> Vec2 {
+//!     Vec2(a.0 * b, a.1 * b)
+//! });
+//!
+//! operator!(inline Vec2: -a -> Vec2 {
+//!     Vec2(-a.0, -a.1)
 //! });
 //!
 //! fn main() {
-//!     assert_eq!(!Left(43), Answer(!43));
+//!     assert_eq!(-(Vec2(1, 2) + &Vec2(3, 4) * 2), Vec2(-7, -10));
 //! }
 //! ```
-
-#![no_std]
-
-#[macro_export]
-macro_rules! operator {
+//!
+//! For the keywords that generate several methods, such as `assign`, `wrapping`, `elementwise`,
+//! `sub_from_add_neg` and `neg_from_sub`, every generated method is marked. The `assign`,
+//! `wrapping` and `elementwise` forms also accept attributes after the `:`, which are applied to
+//! each generated method in the same way:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, Copy, PartialEq)] struct Vec2(i32, i32);
+//! #[derive(Debug, Clone, Copy, PartialEq)] struct Counter(u8);
+//! #[derive(Debug, Clone, Copy, PartialEq)] struct Pair([i32; 2]);
+//!
+//! impl Vec2 {
+//!     const ZERO: Vec2 = Vec2(0, 0);
+//! }
+//!
+//! operator!(Vec2: a + b => Vec2(a.0 + b.0, a.1 + b.1));
+//! operator!(Vec2: a - b => Vec2(a.0 - b.0, a.1 - b.1));
+//! operator!(Vec2: -a -> Vec2 => Vec2(-a.0, -a.1));
+//! operator!(inline assign Vec2: += -=);
+//!
+//! operator!(inline wrapping Counter: + -=);
+//! operator!(inline elementwise Pair: #[cfg(all())] * *=);
+//!
+//! struct Meters(i32);
+//! operator!(Meters: a - b => Meters(a.0 - b.0));
+//! operator!(inline neg_from_sub Meters { zero: Meters(0) });
+//!
+//! #[derive(Clone, Copy)] struct Seconds(i32);
+//! operator!(Seconds: a + b => Seconds(a.0 + b.0));
+//! operator!(Seconds: -a -> Seconds => Seconds(-a.0));
+//! operator!(inline sub_from_add_neg Seconds);
+//!
+//! fn main() {
+//!     let mut v = Vec2(1, 2);
+//!     v += Vec2(3, 4);
+//!     v -= Vec2::ZERO;
+//!     assert_eq!(v, Vec2(4, 6));
+//!
+//!     let mut counter = Counter(250) + Counter(10);
+//!     counter -= Counter(5);
+//!     assert_eq!(counter, Counter(255));
+//!
+//!     let mut pair = Pair([1, 2]) * Pair([3, 4]);
+//!     pair *= Pair([2, 2]);
+//!     assert_eq!(pair, Pair([6, 16]));
+//!
+//!     assert_eq!((-Meters(3)).0, -3);
+//!     assert_eq!((Seconds(5) - Seconds(2)).0, 3);
+//! }
+//! ```
+//!
+//! Similarly, the `must_use` prefix adds `#[must_use]` to the generated method. `inline` and
+//! `must_use` can be combined in either order, and must come before the other keywords. Note that
+//! the compiler already warns about unused results of the overloaded arithmetic and bitwise
+//! operators, whether or not the method is marked.
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, Copy, PartialEq)] struct Vec2(i32, i32);
+//!
+//! operator!(must_use inline Vec2: a + b -> Vec2 {
+//!     Vec2(a.0 + b.0, a.1 + b.1)
+//! });
+//!
+//! operator!(inline must_use ref Vec2, i32: a / b -> Vec2 {
+//!     Vec2(a.0 / b, a.1 / b)
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(&(Vec2(1, 2) + Vec2(3, 4)) / 2, Vec2(2, 3));
+//! }
+//! ```
+//!
+//! Like `inline`, `must_use` marks every method generated by the other keywords:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, Copy, PartialEq)] struct Counter(u8);
+//! #[derive(Debug, Clone, Copy, PartialEq)] struct Pair([i32; 2]);
+//!
+//! operator!(must_use wrapping Counter: + -);
+//! operator!(must_use inline elementwise Pair: + -);
+//! operator!(must_use assign Pair: *=);
+//! operator!(Pair: a * b => Pair([a.0[0] * b.0[0], a.0[1] * b.0[1]]));
+//!
+//! #[derive(Debug, PartialEq)] struct Meters(i32);
+//! operator!(Meters: a - b => Meters(a.0 - b.0));
+//! operator!(must_use neg_from_sub Meters { zero: Meters(0) });
+//!
+//! #[derive(Debug, PartialEq)] struct Seconds(i32);
+//! operator!(Seconds: a + b => Seconds(a.0 + b.0));
+//! operator!(Seconds: -a -> Seconds => Seconds(-a.0));
+//! operator!(must_use inline sub_from_add_neg Seconds);
+//!
+//! fn main() {
+//!     assert_eq!(Counter(0) - Counter(1) + Counter(2), Counter(1));
+//!     let mut pair = Pair([1, 2]) + Pair([3, 4]) - Pair([1, 1]);
+//!     pair *= Pair([2, 3]);
+//!     assert_eq!(pair, Pair([6, 15]));
+//!     assert_eq!(-Meters(3), Meters(-3));
+//!     assert_eq!(Seconds(1) - Seconds(3), Seconds(-2));
+//! }
+//! ```
+//!
+//! Prefix the invocation with `const` to generate a `const` trait implementation, so that the
+//! operator can be used in constant expressions. This is supported for the arithmetic, bitwise,
+//! shift, negation and assignment operators. It requires the `const_ops` feature and a nightly
+//! compiler, and the crate using it must enable `#![feature(const_trait_impl, const_ops)]`.
+//!
+#![cfg_attr(feature = "const_ops", doc = "```")]
+#![cfg_attr(not(feature = "const_ops"), doc = "```ignore")]
+//! #![feature(const_trait_impl, const_ops)]
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, Copy, PartialEq)] struct Fixed(i32);
+//!
+//! operator!(const Fixed: a + b -> Fixed {
+//!     Fixed(a.0 + b.0)
+//! });
+//!
+//! operator!(const Fixed: a *= b {
+//!     a.0 = a.0 * b.0 >> 8;
+//! });
+//!
+//! operator!(const Fixed: -a -> Fixed {
+//!     Fixed(-a.0)
+//! });
+//!
+//! const fn area(w: Fixed, h: Fixed) -> Fixed {
+//!     let mut area = w;
+//!     area *= h;
+//!     area
+//! }
+//!
+//! const THREE: Fixed = Fixed(256) + Fixed(512);
+//! const AREA: Fixed = -area(THREE, Fixed(512));
+//!
+//! fn main() {
+//!     assert_eq!(AREA, Fixed(-1536));
+//! }
+//! ```
+//!
+//! # Generics
+//! Generics can be used on the three types and on the `impl` block.
+//!
+//! Due to disambiguation, generic parameters for the `impl` block are written in `{}` rather
+//! than `<>`.
+//!
+//! ```
+//! # use operator_sugar::*;
+//! use core::ops::Add;
+//! struct Left<T>(T) where T: Add<i32, Output = i32>;
+//! struct Right(i32);
+//! struct Answer(i32);
+//!
+//! operator!(
+//!     {T: Add<i32, Output = i32>}
+//!     Left<T>, Right: a + b -> Answer {
+//!         Answer(a.0 + b.0)
+//!     });
+//! ```
+//!
+//! The generic parameters may also be written in `<>` when they are the first thing in the
+//! invocation, after any modifier keywords and `impl` attributes:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! use core::ops::Add;
+//! struct Left<T>(T);
+//! struct Right(i32);
+//! #[derive(Debug, PartialEq)] struct Answer(i32);
+//!
+//! operator!(
+//!     <T: Add<i32, Output = i32>>
+//!     Left<T>, Right: a + b -> Answer {
+//!         Answer(a.0 + b.0)
+//!     });
+//!
+//! fn main() {
+//!     assert_eq!(Left(1) + Right(2), Answer(3));
+//! }
+//! ```
+//!
+//! A leading `<` is always read as the start of such a list. If the left operand type itself
+//! begins with `<`, as in `<T as Trait>::Output`, write an empty `{}` block before it.
+//!
+//! Bounds may contain commas of their own, for example in associated type bindings:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! use core::fmt::Debug;
+//! use core::ops::Add;
+//! #[derive(Debug, PartialEq)] struct Pair<T, U>(T, U);
+//!
+//! operator!(
+//!     {T: Add<U, Output = V> + Clone + Debug, U: Into<T> + Clone, V}
+//!     Pair<T, U>, Pair<T, U>: a + b -> Pair<V, T> {
+//!         Pair(a.0 + b.1, b.0)
+//!     });
+//!
+//! operator!(
+//!     commutative <T: Add<U, Output = V>, U, V>
+//!     Pair<T, U>, (): a | b -> V {
+//!         a.0 + a.1
+//!     });
+//!
+//! fn main() {
+//!     assert_eq!(Pair(1, 2) + Pair(3, 4), Pair(5, 3));
+//!     assert_eq!(Pair(1, 2) | (), 3);
+//!     assert_eq!(() | Pair(1, 2), 3);
+//! }
+//! ```
+//!
+//! Unary operators accept the same generics and attributes:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! use core::ops::Neg;
+//! struct Wrapper<T>(T);
+//!
+//! operator!(
+//!     {T: Neg<Output = T>}
+//!     Wrapper<T>: #[inline] -a -> Wrapper<T> {
+//!         Wrapper(-a.0)
+//!     });
+//! ```
+//!
+//! The generics block may also introduce lifetimes, which makes it possible to implement operators
+//! on borrowing types:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! struct Slice<'a, T> {
+//!     data: &'a [T],
+//! }
+//!
+//! operator!(
+//!     {'a, T: 'a}
+//!     Slice<'a, T>, usize: a[b] -> &T {
+//!         &a.data[b]
+//!     });
+//!
+//! fn main() {
+//!     let data = [1, 2, 3];
+//!     let slice = Slice { data: &data };
+//!     assert_eq!(slice[1], 2);
+//! }
+//! ```
+//!
+//! Const generic parameters are accepted as well:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, PartialEq)] struct SmallVec<const N: usize>([f32; N]);
+//!
+//! operator!(
+//!     {const N: usize}
+//!     SmallVec<N>, SmallVec<N>: a + b -> SmallVec<N> {
+//!         let mut out = a.0;
+//!         for (x, y) in out.iter_mut().zip(b.0.iter()) {
+//!             *x += y;
+//!         }
+//!         SmallVec(out)
+//!     });
+//!
+//! fn main() {
+//!     assert_eq!(SmallVec([1.0, 2.0]) + SmallVec([3.0, 4.0]), SmallVec([4.0, 6.0]));
+//! }
+//! ```
+//!
+//! Bounds that relate several parameters can be written in a `where` clause between the return
+//! type and the body. The clause is placed on the generated `impl` block.
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug)] struct Left<T>(T);
+//! #[derive(Debug)] struct Right<U>(U);
+//! #[derive(Debug, PartialEq)] struct Answer<U>(U);
+//!
+//! operator!(
+//!     {T, U}
+//!     Left<T>, Right<U>: a + b -> Answer<U> where T: Into<U>, U: core::ops::Add<Output = U> {
+//!         Answer(a.0.into() + b.0)
+//!     });
+//!
+//! fn main() {
+//!     assert_eq!(Left(1u8) + Right(2u32), Answer(3u32));
+//! }
+//! ```
+//!
+//! The clause may also constrain the output type:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! use core::iter::FromIterator;
+//! use core::ops::Mul;
+//!
+//! struct Factors<T>(Vec<T>);
+//! #[derive(Debug, PartialEq)] struct Product<T>(Vec<T>);
+//!
+//! impl<T> FromIterator<T> for Product<T> {
+//!     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+//!         Product(iter.into_iter().collect())
+//!     }
+//! }
+//!
+//! operator!(
+//!     {T}
+//!     Factors<T>, T: a * b -> Product<T>
+//!     where T: Mul<Output = T> + Copy, Product<T>: FromIterator<T> {
+//!         a.0.into_iter().map(|x| x * b).collect()
+//!     });
+//!
+//! fn main() {
+//!     assert_eq!(Factors(vec![1, 2, 3]) * 2, Product(vec![2, 4, 6]));
+//! }
+//! ```
+//!
+//! Higher-ranked trait bounds are passed through unchanged, both in the `where` clause and in the
+//! generics block:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! use core::ops::Add;
+//! #[derive(Debug, PartialEq)] struct Wrapper<T>(T);
+//!
+//! operator!(
+//!     {T}
+//!     Wrapper<T>, Wrapper<T>: a + b -> Wrapper<T> where for<'a> &'a T: Add<&'a T, Output = T> {
+//!         Wrapper(&a.0 + &b.0)
+//!     });
+//!
+//! operator!(
+//!     {T, F: for<'a> Fn(&'a T) -> T}
+//!     Wrapper<T>, F: a * b -> Wrapper<T> {
+//!         Wrapper(b(&a.0))
+//!     });
+//!
+//! fn main() {
+//!     assert_eq!(Wrapper(1) + Wrapper(2), Wrapper(3));
+//!     assert_eq!(Wrapper(3) * (|x: &i32| x * 2), Wrapper(6));
+//! }
+//! ```
+//!
+//! The output type may be an associated type of a generic parameter:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! struct Wrap<T>(T);
+//!
+//! operator!(
+//!     {T: Iterator}
+//!     Wrap<T>, usize: a % b -> T::Item {
+//!         let mut iter = a.0;
+//!         iter.nth(b).expect("index out of range")
+//!     });
+//!
+//! fn main() {
+//!     assert_eq!(Wrap("abc".chars()) % 1, 'b');
+//! }
+//! ```
+//!
+//! # References
+//! The operand types can be references, with the lifetimes elided.
+//! In particular, assignment operators can take the right operand by reference
+//! to avoid moving expensive values:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! struct BigInt(Vec<u32>);
+//!
+//! operator!(BigInt, &BigInt: a += b {
+//!     for (x, y) in a.0.iter_mut().zip(&b.0) {
+//!         *x += y;
+//!     }
+//! });
+//!
+//! fn main() {
+//!     let mut total = BigInt(vec![1, 2]);
+//!     let delta = BigInt(vec![3, 4]);
+//!     total += &delta;
+//!     total += &delta;
+//!     assert_eq!(total.0, vec![7, 10]);
+//! }
+//! ```
+//!
+//! Generics work the same way with references:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! use core::ops::SubAssign;
+//! struct Histogram<T> { buckets: Vec<T> }
+//!
+//! operator!({T: SubAssign + Copy} Histogram<T>, &Histogram<T>: a -= b {
+//!     for (i, v) in b.buckets.iter().enumerate() {
+//!         a.buckets[i] -= *v;
+//!     }
+//! });
+//!
+//! fn main() {
+//!     let mut hist = Histogram { buckets: vec![5, 6] };
+//!     hist -= &Histogram { buckets: vec![1, 2] };
+//!     assert_eq!(hist.buckets, vec![4, 4]);
+//! }
+//! ```
+//!
+//! The body of an assignment operator can delegate to an operator implemented on references:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, PartialEq)] struct Mat2([[i32; 2]; 2]);
+//!
+//! operator!(&Mat2, &Mat2: a * b -> Mat2 {
+//!     let mut out = [[0; 2]; 2];
+//!     for i in 0..2 {
+//!         for j in 0..2 {
+//!             out[i][j] = a.0[i][0] * b.0[0][j] + a.0[i][1] * b.0[1][j];
+//!         }
+//!     }
+//!     Mat2(out)
+//! });
+//!
+//! operator!(Mat2, &Mat2: a *= b {
+//!     *a = &*a * b;
+//! });
+//!
+//! fn main() {
+//!     let mut mat = Mat2([[1, 1], [0, 1]]);
+//!     mat *= &Mat2([[1, 1], [0, 1]]);
+//!     assert_eq!(mat, Mat2([[1, 2], [0, 1]]));
+//! }
+//! ```
+//!
+//! The elided lifetime accepts borrows of any lifetime, e.g. from a temporary iterator:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! struct Bloom { bits: Vec<u64> }
+//!
+//! operator!(Bloom, &Bloom: a |= b {
+//!     for (x, y) in a.bits.iter_mut().zip(&b.bits) {
+//!         *x |= y;
+//!     }
+//! });
+//!
+//! fn main() {
+//!     let filters = vec![Bloom { bits: vec![1, 0] }, Bloom { bits: vec![0, 2] }];
+//!     let mut merged = Bloom { bits: vec![0, 0] };
+//!     for filter in &filters {
+//!         merged |= filter;
+//!     }
+//!     assert_eq!(merged.bits, vec![1, 2]);
+//! }
+//! ```
+//!
+//! The borrowed operand is left untouched, so it can be reused afterwards:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! struct BitVec(Vec<bool>);
+//!
+//! operator!(BitVec, &BitVec: a &= b {
+//!     for (x, y) in a.0.iter_mut().zip(&b.0) {
+//!         *x &= *y;
+//!     }
+//! });
+//!
+//! fn main() {
+//!     let mask = BitVec(vec![true, false, true]);
+//!     let mut set = BitVec(vec![true, true, false]);
+//!     set &= &mask;
+//!     assert_eq!(set.0, vec![true, false, false]);
+//!     assert_eq!(mask.0, vec![true, false, true]);
+//! }
+//! ```
+//!
+//! Binary operators can borrow the left operand, so that it is not consumed:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, PartialEq)] struct BigInt(Vec<u32>);
+//!
+//! operator!(&BigInt, BigInt: a + b -> BigInt {
+//!     BigInt(a.0.iter().zip(b.0).map(|(x, y)| x + y).collect())
+//! });
+//!
+//! fn main() {
+//!     let numbers = vec![BigInt(vec![1, 2])];
+//!     assert_eq!(&numbers[0] + BigInt(vec![3, 4]), BigInt(vec![4, 6]));
+//! }
+//! ```
+//!
+//! or the right operand:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, PartialEq)] struct Matrix(Vec<i32>);
+//!
+//! operator!(Matrix, &Matrix: a * b -> Matrix {
+//!     Matrix(a.0.into_iter().zip(&b.0).map(|(x, y)| x * y).collect())
+//! });
+//!
+//! fn main() {
+//!     let transform = Matrix(vec![2, 3]);
+//!     assert_eq!(Matrix(vec![1, 2]) * &transform, Matrix(vec![2, 6]));
+//!     assert_eq!(Matrix(vec![3, 4]) * &transform, Matrix(vec![6, 12]));
+//! }
+//! ```
+//!
+//! or both operands. The two references get independent elided lifetimes:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, PartialEq)] struct Polynomial(Vec<i32>);
+//!
+//! operator!(&Polynomial, &Polynomial: a + b -> Polynomial {
+//!     Polynomial(a.0.iter().zip(&b.0).map(|(x, y)| x + y).collect())
+//! });
+//!
+//! fn add_all<'a, 'b>(base: &'a Polynomial, others: &'b [Polynomial]) -> Vec<Polynomial> {
+//!     others.iter().map(|other| base + other).collect()
+//! }
+//!
+//! fn main() {
+//!     let base = Polynomial(vec![1, 1]);
+//!     let sums = add_all(&base, &[Polynomial(vec![1, 2]), Polynomial(vec![3, 4])]);
+//!     assert_eq!(sums, vec![Polynomial(vec![2, 3]), Polynomial(vec![4, 5])]);
+//! }
+//! ```
+//!
+//! Generics are passed through to borrowed operands as usual:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! use core::ops::{Add, Mul};
+//! #[derive(Debug, PartialEq)] struct Mat2<T>([[T; 2]; 2]);
+//!
+//! operator!(
+//!     {T: Copy + Add<Output = T> + Mul<Output = T>}
+//!     &Mat2<T>, &Mat2<T>: a * b -> Mat2<T> {
+//!         let cell = |i: usize, j: usize| a.0[i][0] * b.0[0][j] + a.0[i][1] * b.0[1][j];
+//!         Mat2([[cell(0, 0), cell(0, 1)], [cell(1, 0), cell(1, 1)]])
+//!     });
+//!
+//! fn main() {
+//!     let m = Mat2([[1, 1], [1, 0]]);
+//!     assert_eq!(&m * &m, Mat2([[2, 1], [1, 1]]));
+//!     let f = Mat2([[1.0, 0.0], [0.0, 2.0]]);
+//!     assert_eq!(&f * &f, Mat2([[1.0, 0.0], [0.0, 4.0]]));
+//! }
+//! ```
+//!
+//! Attributes apply in the same positions as well:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! use core::ops::BitAnd;
+//! #[derive(Debug, PartialEq)] struct BitVec<B>(Vec<B>);
+//!
+//! operator!(
+//!     #[doc = "Intersects two bitsets into a new one."]
+//!     {B: Copy + BitAnd<Output = B>} &BitVec<B>, &BitVec<B>:
+//!     #[inline] a & b -> BitVec<B> {
+//!         BitVec(a.0.iter().zip(&b.0).map(|(&x, &y)| x & y).collect())
+//!     });
+//!
+//! fn main() {
+//!     let (x, y) = (BitVec(vec![0b1100u8]), BitVec(vec![0b1010u8]));
+//!     assert_eq!(&x & &y, BitVec(vec![0b1000]));
+//!     assert_eq!(x, BitVec(vec![0b1100]));
+//! }
+//! ```
+//!
+//! Prefix the invocation with `ref` to also implement the operator for all combinations of
+//! references to the operands, like the standard library does for primitive types.
+//! The reference impls dereference the operands and delegate to the by-value impl,
+//! so both operand types must implement `Copy`.
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, Copy, PartialEq)] struct Vec2(i32, i32);
+//!
+//! operator!(ref Vec2, Vec2: a + b -> Vec2 {
+//!     Vec2(a.0 + b.0, a.1 + b.1)
+//! });
+//!
+//! fn main() {
+//!     let (v, w) = (Vec2(1, 2), Vec2(3, 4));
+//!     assert_eq!(v + w, Vec2(4, 6));
+//!     assert_eq!(&v + w, Vec2(4, 6));
+//!     assert_eq!(v + &w, Vec2(4, 6));
+//!     assert_eq!(&v + &w, Vec2(4, 6));
+//! }
+//! ```
+//!
+//! With `ref`, unary operators are also implemented for a reference to the operand, and
+//! assignment operators for a reference to the right operand. `==` and `!=` are only implemented
+//! by value, since the standard library already compares references through it:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, Copy, PartialEq)] struct Vec2(i32, i32);
+//!
+//! operator!(ref Vec2: -a -> Vec2 => Vec2(-a.0, -a.1));
+//! operator!(ref Vec2: a += b {
+//!     a.0 += b.0;
+//!     a.1 += b.1;
+//! });
+//!
+//! fn main() {
+//!     let mut v = Vec2(1, 2);
+//!     v += &Vec2(3, 4);
+//!     assert_eq!(-&v, Vec2(-4, -6));
+//! }
+//! ```
+//!
+//! For types that are `Clone` but not `Copy`, such as heap-backed numbers, the `ref_clone` prefix
+//! implements the binary operator for the reference combinations by cloning the borrowed operands
+//! instead:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, PartialEq)] struct BigInt(Vec<u32>);
+//!
+//! operator!(ref_clone BigInt: a + b {
+//!     BigInt(a.0.iter().zip(&b.0).map(|(x, y)| x + y).collect())
+//! });
+//!
+//! fn main() {
+//!     let (x, y) = (BigInt(vec![1, 2]), BigInt(vec![3, 4]));
+//!     assert_eq!(&x + &y, BigInt(vec![4, 6]));
+//!     assert_eq!(x + &y, BigInt(vec![4, 6]));
+//! }
+//! ```
+//!
+//! # Assignment operators from binary operators
+//! Prefix the invocation with `assign` to implement assignment operators by delegating to the
+//! corresponding binary operators, which must have `Self` as the output type.
+//! If only one type is specified, the right operand type is `Self` as well.
+//! The type must implement `Clone`, because the left operand is cloned before being passed to the
+//! binary operator.
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, Copy, PartialEq)] struct Vector(i32, i32);
+//!
+//! operator!(Vector, Vector: a + b -> Vector {
+//!     Vector(a.0 + b.0, a.1 + b.1)
+//! });
+//!
+//! operator!(Vector, Vector: a - b -> Vector {
+//!     Vector(a.0 - b.0, a.1 - b.1)
+//! });
+//!
+//! operator!(assign Vector: += -=);
+//!
+//! fn main() {
+//!     let mut vector = Vector(1, 2);
+//!     vector += Vector(3, 4);
+//!     vector -= Vector(2, 2);
+//!     assert_eq!(vector, Vector(2, 4));
+//! }
+//! ```
+//!
+//! Types that are not `Copy` can be used as well:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, PartialEq)] struct Polynomial(Vec<i32>);
+//!
+//! operator!(Polynomial, Polynomial: a + b -> Polynomial {
+//!     Polynomial(a.0.iter().zip(&b.0).map(|(x, y)| x + y).collect())
+//! });
+//!
+//! operator!(assign Polynomial: +=);
+//!
+//! fn main() {
+//!     let mut poly = Polynomial(vec![1, 2]);
+//!     poly += Polynomial(vec![3, 4]);
+//!     assert_eq!(poly, Polynomial(vec![4, 6]));
+//! }
+//! ```
+//!
+//! A different right operand type can be specified like in other operators:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, Copy, PartialEq)] struct Vector(f64, f64);
+//!
+//! operator!(Vector, f64: a * b -> Vector {
+//!     Vector(a.0 * b, a.1 * b)
+//! });
+//!
+//! operator!(assign Vector, f64: *=);
+//!
+//! fn main() {
+//!     let mut vector = Vector(1.0, 2.0);
+//!     vector *= 1.5;
+//!     assert_eq!(vector, Vector(1.5, 3.0));
+//! }
+//! ```
+//!
+//! Several operators sharing the same right operand type can be listed together:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, Copy, PartialEq)] struct BitBuf(u64);
+//!
+//! operator!(BitBuf, u32: a << b -> BitBuf {
+//!     BitBuf(a.0 << b)
+//! });
+//!
+//! operator!(BitBuf, u32: a >> b -> BitBuf {
+//!     BitBuf(a.0 >> b)
+//! });
+//!
+//! operator!(assign BitBuf, u32: <<= >>=);
+//!
+//! fn main() {
+//!     let mut buf = BitBuf(1);
+//!     buf <<= 4;
+//!     buf >>= 1;
+//!     assert_eq!(buf, BitBuf(8));
+//! }
+//! ```
+//!
+//! The binary operator must be implemented, otherwise the trait bound is reported as unsatisfied:
+//!
+//! ```compile_fail
+//! # use operator_sugar::*;
+//! #[derive(Clone, Copy)] struct Flags(u32);
+//!
+//! // error[E0277]: no implementation for `Flags | Flags`
+//! operator!(assign Flags: |=);
+//! ```
+//!
+//! A binary operator and its assignment operator can also be declared together by appending
+//! `with assign`, under the same requirements:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, Copy, PartialEq)] struct Vector(i32, i32);
+//!
+//! operator!(Vector, Vector: a + b -> Vector {
+//!     Vector(a.0 + b.0, a.1 + b.1)
+//! } with assign);
+//!
+//! fn main() {
+//!     let mut vector = Vector(1, 2) + Vector(3, 4);
+//!     vector += Vector(1, 1);
+//!     assert_eq!(vector, Vector(5, 7));
+//! }
+//! ```
+//!
+//! As with `assign`, the left operand is cloned, so the type only needs to implement `Clone`:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, PartialEq)] struct Money { currency: String, cents: i64 }
+//!
+//! operator!(Money, Money: a - b -> Money {
+//!     assert_eq!(a.currency, b.currency);
+//!     Money { currency: a.currency, cents: a.cents - b.cents }
+//! } with assign);
+//!
+//! fn main() {
+//!     let mut wallet = Money { currency: "USD".to_string(), cents: 500 };
+//!     wallet -= Money { currency: "USD".to_string(), cents: 120 };
+//!     assert_eq!(wallet.cents, 380);
+//! }
+//! ```
+//!
+//! The assignment operator uses the same right operand type as the binary operator:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, PartialEq)] struct Matrix(Vec<f64>);
+//!
+//! operator!(Matrix, f64: a * b -> Matrix {
+//!     Matrix(a.0.into_iter().map(|x| x * b).collect())
+//! } with assign);
+//!
+//! fn main() {
+//!     let mut matrix = Matrix(vec![1.0, 2.0]);
+//!     matrix *= 2.0;
+//!     assert_eq!(matrix, Matrix(vec![2.0, 4.0]));
+//! }
+//! ```
+//!
+//! This works the same way for bitwise operators:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, Copy, PartialEq)] struct BitBoard(u64);
+//!
+//! operator!(BitBoard, u64: a ^ b -> BitBoard {
+//!     BitBoard(a.0 ^ b)
+//! } with assign);
+//!
+//! fn main() {
+//!     let mut board = BitBoard(0b1010);
+//!     board ^= 0b0110;
+//!     assert_eq!(board, BitBoard(0b1100));
+//!     assert_eq!(board ^ 0b1100, BitBoard(0));
+//! }
+//! ```
+//!
+//! The assignment operator calls the binary operator implementation rather than the primitive
+//! operator, so custom semantics such as rounding are preserved:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, Copy, PartialEq)] struct Fixed(i32);
+//!
+//! operator!(Fixed, u32: a >> b -> Fixed {
+//!     // Round to nearest instead of truncating.
+//!     Fixed((a.0 + (1 << b >> 1)) >> b)
+//! } with assign);
+//!
+//! fn main() {
+//!     let mut fixed = Fixed(6);
+//!     fixed >>= 2;
+//!     assert_eq!(fixed, Fixed(2));
+//! }
+//! ```
+//!
+//! The output type must be the left operand type for the assignment operator to be implemented:
+//!
+//! ```compile_fail
+//! # use operator_sugar::*;
+//! #[derive(Clone)] struct Matrix(Vec<f64>);
+//!
+//! operator!(Matrix, f64: a * b -> Vec<f64> {
+//!     a.0.into_iter().map(|x| x * b).collect()
+//! } with assign);
+//! ```
+//!
+//! # Commutative operators
+//! Prefix the invocation with `commutative` to also implement the operator with the operand types
+//! swapped. In the swapped impl, `a` is still bound to the operand of the first type and `b` to
+//! the operand of the second type, so the same body is used for both impls.
+//!
+//! This is only accepted for `+`, `*`, `&`, `|` and `^`.
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, PartialEq)] struct Length(f64);
+//! struct Meters(f64);
+//! struct Feet(f64);
+//!
+//! operator!(commutative Meters, Feet: a + b -> Length {
+//!     Length(a.0 + b.0 * 0.3048)
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(Meters(1.0) + Feet(10.0), Length(4.048));
+//!     assert_eq!(Feet(10.0) + Meters(1.0), Length(4.048));
+//! }
+//! ```
+//!
+//! Attributes and generics are applied to both impls:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, PartialEq)] struct PolyDense(Vec<i32>);
+//! struct PolySparse(Vec<(usize, i32)>);
+//!
+//! operator!(commutative #[doc = "Adds a sparse polynomial to a dense polynomial."]
+//!     PolyDense, PolySparse: #[inline] a + b -> PolyDense {
+//!         let mut coefs = a.0;
+//!         for (i, c) in b.0 {
+//!             coefs[i] += c;
+//!         }
+//!         PolyDense(coefs)
+//!     });
+//!
+//! fn main() {
+//!     assert_eq!(PolyDense(vec![1, 2]) + PolySparse(vec![(1, 3)]), PolyDense(vec![1, 5]));
+//!     assert_eq!(PolySparse(vec![(0, 3)]) + PolyDense(vec![1, 2]), PolyDense(vec![4, 2]));
+//! }
+//! ```
+//!
+//! The output type is the same in both directions:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! use std::collections::{BTreeSet, HashSet};
+//! struct HashSetWrap(HashSet<i32>);
+//! struct BTreeSetWrap(BTreeSet<i32>);
+//!
+//! operator!(commutative HashSetWrap, BTreeSetWrap: a & b -> HashSetWrap {
+//!     HashSetWrap(a.0.into_iter().filter(|x| b.0.contains(x)).collect())
+//! });
+//!
+//! fn main() {
+//!     let hash = || HashSetWrap([1, 2, 3].iter().copied().collect());
+//!     let btree = || BTreeSetWrap([2, 3, 4].iter().copied().collect());
+//!     assert_eq!((hash() & btree()).0.len(), 2);
+//!     assert_eq!((btree() & hash()).0.len(), 2);
+//! }
+//! ```
+//!
+//! Generic bounds are propagated to both impls as well:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! use std::collections::BTreeSet;
+//! struct VecSet<T>(Vec<T>);
+//! struct TreeSet<T>(BTreeSet<T>);
+//!
+//! operator!(commutative {T: Ord} VecSet<T>, TreeSet<T>: a | b -> TreeSet<T> {
+//!     let mut set = b.0;
+//!     set.extend(a.0);
+//!     TreeSet(set)
+//! });
+//!
+//! fn main() {
+//!     let vec = || VecSet(vec![1, 2]);
+//!     let tree = || TreeSet([2, 3].iter().copied().collect());
+//!     assert_eq!((vec() | tree()).0.len(), 3);
+//!     assert_eq!((tree() | vec()).0.len(), 3);
+//! }
+//! ```
+//!
+//! Since the impls only need one of the types to be local, this also allows a primitive scalar
+//! on the left side:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, PartialEq)] struct Vec3(f64, f64, f64);
+//!
+//! operator!(commutative Vec3, f64: a * b -> Vec3 {
+//!     Vec3(a.0 * b, a.1 * b, a.2 * b)
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(2.0 * Vec3(1.0, 2.0, 3.0), Vec3(2.0, 4.0, 6.0));
+//!     assert_eq!(Vec3(1.0, 2.0, 3.0) * 2.0, Vec3(2.0, 4.0, 6.0));
+//! }
+//! ```
+//!
+//! `scalar_commutative` is an alias of `commutative` for this case, which reads more naturally
+//! when the scalar type is written first:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, PartialEq)] struct Vec3(f64, f64, f64);
+//!
+//! operator!(scalar_commutative f64, Vec3: k * v -> Vec3 {
+//!     Vec3(k * v.0, k * v.1, k * v.2)
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(2.0 * Vec3(1.0, 2.0, 3.0), Vec3(2.0, 4.0, 6.0));
+//!     assert_eq!(Vec3(1.0, 2.0, 3.0) * 2.0, Vec3(2.0, 4.0, 6.0));
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! # use operator_sugar::*;
+//! struct Meters(f64);
+//! struct Feet(f64);
+//!
+//! // error: The `-` operator is not commutative
+//! operator!(commutative Meters, Feet: a - b -> f64 {
+//!     a.0 - b.0 * 0.3048
+//! });
+//! ```
+//!
+//! To implement any operator for the swapped operand types, use `swap` instead. The swapped impl
+//! runs the same body with the bindings exchanged, so the body decides what the swapped operator
+//! means; it is up to the caller to make sure that is correct:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, PartialEq)] struct Millimeters(i64);
+//! #[derive(Debug, PartialEq)] struct Centimeters(i64);
+//!
+//! // the distance between two lengths
+//! operator!(swap Millimeters, Centimeters: a - b -> Millimeters {
+//!     Millimeters((a.0 - b.0 * 10).abs())
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(Millimeters(5) - Centimeters(3), Millimeters(25));
+//!     assert_eq!(Centimeters(3) - Millimeters(5), Millimeters(25));
+//! }
+//! ```
+//!
+//! # Operators from other operators
+//! Prefix a type with `sub_from_add_neg` to implement `Sub` as the addition of the negated right
+//! operand, `a + -b`. The type must already implement `Add` and `Neg` with itself as the output.
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, PartialEq)] struct Vec3(i32, i32, i32);
+//!
+//! operator!(Vec3: a + b => Vec3(a.0 + b.0, a.1 + b.1, a.2 + b.2));
+//! operator!(Vec3: -a -> Vec3 => Vec3(-a.0, -a.1, -a.2));
+//! operator!(sub_from_add_neg Vec3);
+//!
+//! fn main() {
+//!     assert_eq!(Vec3(1, 2, 3) - Vec3(3, 2, 1), Vec3(-2, 0, 2));
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! # use operator_sugar::*;
+//! struct Vec3(i32, i32, i32);
+//!
+//! operator!(Vec3: a + b => Vec3(a.0 + b.0, a.1 + b.1, a.2 + b.2));
+//! // error: the trait bound `Vec3: Neg` is not satisfied
+//! operator!(sub_from_add_neg Vec3);
+//! ```
+//!
+//! Similarly, `neg_from_sub` implements `Neg` by subtracting the operand from the given zero
+//! value. The type must already implement `Sub` with itself as the output.
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, PartialEq)] struct Vec3(i32, i32, i32);
+//!
+//! impl Vec3 {
+//!     const ZERO: Vec3 = Vec3(0, 0, 0);
+//! }
+//!
+//! operator!(Vec3: a - b => Vec3(a.0 - b.0, a.1 - b.1, a.2 - b.2));
+//! operator!(neg_from_sub Vec3 { zero: Vec3::ZERO });
+//!
+//! fn main() {
+//!     assert_eq!(-Vec3(1, -2, 3), Vec3(-1, 2, -3));
+//! }
+//! ```
+//!
+//! # Wrapping arithmetic
+//! Prefix the invocation with `wrapping` and list the operators after the `:` to implement them
+//! with wrapping semantics on the first field, such as `u32::wrapping_add` for `+`. The
+//! arithmetic operators `+`, `-`, `*`, `/` and `%` and their assignment operators are supported.
+//! This is useful for cycle counters and hash accumulators that must wrap around on overflow.
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, Copy, PartialEq)] struct Counter(u8);
+//!
+//! operator!(wrapping Counter: + - += *=);
+//!
+//! fn main() {
+//!     assert_eq!(Counter(250) + Counter(10), Counter(4));
+//!     assert_eq!(Counter(1) - Counter(2), Counter(255));
+//!     let mut hash = Counter(200);
+//!     hash += Counter(100);
+//!     hash *= Counter(3);
+//!     assert_eq!(hash, Counter(132));
+//! }
+//! ```
+//!
+//! # Elementwise operators
+//! For a wrapper around an array, prefix the invocation with `elementwise` and list the operators
+//! after the `:` to apply them to each pair of elements. The arithmetic and bitwise operators and
+//! their assignment operators are supported. The elements must be `Copy` for the binary operators.
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, Copy, PartialEq)] struct VecN<const N: usize>([f32; N]);
+//!
+//! operator!(elementwise {const N: usize} VecN<N>: + - * +=);
+//!
+//! fn main() {
+//!     let mut v = VecN([1.0, 2.0, 3.0]) * VecN([2.0, 2.0, 2.0]) - VecN([1.0, 1.0, 1.0]);
+//!     v += VecN([0.5, 0.5, 0.5]);
+//!     assert_eq!(v, VecN([1.5, 3.5, 5.5]));
+//!     assert_eq!(VecN([1.0, 2.0]) + VecN([3.0, 4.0]), VecN([4.0, 6.0]));
+//! }
+//! ```
+//!
+//! # Multiple operators
+//! The `operators!` macro implements several operators for the same types at once. The types are
+//! written once, followed by a block of operators in the same syntax as after the `:` of
+//! `operator!`:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, Copy)] struct Vec3(f64, f64, f64);
+//!
+//! operators!(Vec3, Vec3 {
+//!     a + b -> Vec3 {
+//!         Vec3(a.0 + b.0, a.1 + b.1, a.2 + b.2)
+//!     }
+//!
+//!     #[inline]
+//!     a - b -> Vec3 {
+//!         Vec3(a.0 - b.0, a.1 - b.1, a.2 - b.2)
+//!     } with assign
+//!
+//!     a == b => a.0 == b.0 && a.1 == b.1 && a.2 == b.2;
+//! });
+//!
+//! operators!(Vec3 {
+//!     -a -> Vec3 => Vec3(-a.0, -a.1, -a.2),
+//!     a * b -> f64 => a.0 * b.0 + a.1 * b.1 + a.2 * b.2,
+//! });
+//!
+//! fn main() {
+//!     let mut v = Vec3(1.0, 2.0, 3.0) + Vec3(1.0, 1.0, 1.0);
+//!     v -= Vec3(2.0, 3.0, 4.0);
+//!     assert!(-v == Vec3(0.0, 0.0, 0.0));
+//!     assert_eq!(Vec3(1.0, 2.0, 3.0) * Vec3(1.0, 1.0, 1.0), 6.0);
+//! }
+//! ```
+//!
+//! Modifier keywords, attributes and generics in front of the types apply to every operator in
+//! the block:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! use core::ops::{Add, Sub};
+//! #[derive(Debug, Clone, Copy, PartialEq)] struct Wrapper<T>(T);
+//!
+//! operators!(inline ref {T: Copy + Add<Output = T> + Sub<Output = T>} Wrapper<T> {
+//!     a + b -> Wrapper<T> => Wrapper(a.0 + b.0),
+//!     a - b -> Wrapper<T> => Wrapper(a.0 - b.0),
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(&Wrapper(3) + &Wrapper(4) - Wrapper(2), Wrapper(5));
+//! }
+//! ```
+//!
+//! The same block can be written as `operator!(for Type { ... })`. Appending `with refs` implements
+//! every operator in the block for references as well, in the same way as the `ref` keyword.
+//! Modifier keywords go after `for`:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, Copy)] struct Vec2(f32, f32);
+//!
+//! operator!(for inline Vec2 {
+//!     a + b => Vec2(a.0 + b.0, a.1 + b.1),
+//!     a - b => Vec2(a.0 - b.0, a.1 - b.1),
+//!     -a -> Vec2 => Vec2(-a.0, -a.1),
+//!     a * b -> f32 => a.0 * b.0 + a.1 * b.1,
+//!     a += b {
+//!         a.0 += b.0;
+//!         a.1 += b.1;
+//!     }
+//! } with refs);
+//!
+//! fn main() {
+//!     let (v, w) = (Vec2(1.0, 2.0), Vec2(3.0, 4.0));
+//!     let mut sum = &v + &w - v;
+//!     sum += &w;
+//!     assert_eq!(&sum * -&w, -50.0);
+//! }
+//! ```
+//!
+//! Several right operand types can also be listed in `[]` to implement the same operator for each
+//! of them. The body is checked separately for every type:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, PartialEq)] struct Vec3(f64, f64, f64);
+//!
+//! operator!(commutative Vec3, [f64, f32, i32]: a * b -> Vec3 {
+//!     let k = f64::from(b);
+//!     Vec3(a.0 * k, a.1 * k, a.2 * k)
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(Vec3(1.0, 2.0, 3.0) * 2.0, Vec3(2.0, 4.0, 6.0));
+//!     assert_eq!(2.0f32 * Vec3(1.0, 2.0, 3.0), Vec3(2.0, 4.0, 6.0));
+//!     assert_eq!(Vec3(1.0, 2.0, 3.0) * 2, Vec3(2.0, 4.0, 6.0));
+//! }
+//! ```
+//!
+//! The list can also be written in `{}`, which is convenient for stamping out an operator over the
+//! primitive types:
+//!
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, PartialEq)] struct BitBuf(u64);
+//!
+//! operator!(BitBuf, {u8, u16, u32, u64}: a << b -> BitBuf {
+//!     BitBuf(a.0 << b)
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(BitBuf(1) << 3u8, BitBuf(8));
+//!     assert_eq!(BitBuf(1) << 40u64, BitBuf(1 << 40));
+//! }
+//! ```
+//!
+//! # `num-traits` integration
+//! With the `num-traits` feature, the `num` prefix implements `num_traits::Zero` and
+//! `num_traits::One` from the given values, so that a numeric newtype can be used with generic
+//! numeric algorithms. Either field can be omitted. `Zero::is_zero` compares with the zero value, so
+//! the type must implement `PartialEq` for `zero`. The `inline` and `must_use` prefixes apply to
+//! the generated methods.
+//!
+#![cfg_attr(feature = "num-traits", doc = "```")]
+#![cfg_attr(not(feature = "num-traits"), doc = "```ignore")]
+//! # use operator_sugar::*;
+//! use num_traits::{One, Zero};
+//! #[derive(Debug, Clone, Copy, PartialEq)] struct Scalar(f64);
+//!
+//! operators!(Scalar {
+//!     a + b => Scalar(a.0 + b.0),
+//!     a * b => Scalar(a.0 * b.0),
+//! });
+//!
+//! operator!(inline num Scalar {
+//!     zero: Scalar(0.0),
+//!     one: Scalar(1.0),
+//! });
+//!
+//! fn product<T: One + Copy>(values: &[T]) -> T {
+//!     values.iter().fold(T::one(), |acc, &x| acc * x)
+//! }
+//!
+//! fn main() {
+//!     assert_eq!(product(&[Scalar(2.0), Scalar(3.0)]), Scalar(6.0));
+//!     assert!(Scalar::zero().is_zero());
+//! }
+//! ```
+//!
+//! # Derive macros
+//! For newtypes with a single field, the operators usually just delegate to the field. With the
+//! `derive` feature, this crate re-exports derive macros from the `operator-sugar-derive` crate
+//! for `Add`, `Sub`, `Mul`, `Div`, `Rem`, `BitAnd`, `BitOr`, `BitXor`, `Neg` and `Not`, which
+//! implement the operator with `Self` as the operand and output types:
+//!
+#![cfg_attr(feature = "derive", doc = "```")]
+#![cfg_attr(not(feature = "derive"), doc = "```ignore")]
+//! # use operator_sugar::*;
+//! #[derive(Debug, PartialEq, Add, Sub, Neg)]
+//! struct Meters(f64);
+//!
+//! operator!(Meters, f64: a * b -> Meters {
+//!     Meters(a.0 * b)
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(-(Meters(1.0) + Meters(2.0) - Meters(0.5)) * 2.0, Meters(-5.0));
+//! }
+//! ```
+//!
+//! # List of operators
+//! For conciseness, these definitions are defined for each of the following examples:
+//! ```no_run
+//! use operator_sugar::*;
+//! #[derive(Debug)] struct Left(i32);
+//! #[derive(Debug)] struct Right(i32);
+//! #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
+//! ```
+//!
+//! ## Addition
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
+//! #
+//! operator!(Left, Right: a + b -> Answer {
+//!     Answer(a.0 + b.0)
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(Left(1) + Right(2), Answer(3));
+//! }
+//! ```
+//!
+//! ## Subtraction
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
+//! #
+//! operator!(Left, Right: a - b -> Answer {
+//!     Answer(a.0 - b.0)
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(Left(1) - Right(2), Answer(-1));
+//! }
+//! ```
+//!
+//! ## Multiplication
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
+//! #
+//! operator!(Left, Right: a * b -> Answer {
+//!     Answer(a.0 * b.0)
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(Left(3) * Right(2), Answer(6));
+//! }
+//! ```
+//!
+//! ## Division
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
+//! #
+//! operator!(Left, Right: a / b -> Answer {
+//!     Answer(a.0 / b.0)
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(Left(8) / Right(2), Answer(4));
+//! }
+//! ```
+//!
+//! ## Remainder
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
+//! #
+//! operator!(Left, Right: a % b -> Answer {
+//!     Answer(a.0 % b.0)
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(Left(9) % Right(5), Answer(4));
+//! }
+//! ```
+//!
+//! ## Bitwise AND
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
+//! #
+//! operator!(Left, Right: a & b -> Answer {
+//!     Answer(a.0 & b.0)
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(Left(5) & Right(6), Answer(4));
+//! }
+//! ```
+//!
+//! ## Bitwise OR
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
+//! #
+//! operator!(Left, Right: a | b -> Answer {
+//!     Answer(a.0 | b.0)
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(Left(5) | Right(6), Answer(7));
+//! }
+//! ```
+//!
+//! ## Bitwise XOR
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
+//! #
+//! operator!(Left, Right: a ^ b -> Answer {
+//!     Answer(a.0 ^ b.0)
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(Left(5) ^ Right(6), Answer(3));
+//! }
+//! ```
+//!
+//! ## Shift-left
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
+//! #
+//! operator!(Left, Right: a << b -> Answer {
+//!     Answer(a.0 << b.0)
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(Left(5) << Right(3), Answer(40));
+//! }
+//! ```
+//!
+//! ## Shift-right
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
+//! #
+//! operator!(Left, Right: a >> b -> Answer {
+//!     Answer(a.0 >> b.0)
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(Left(43) >> Right(3), Answer(5));
+//! }
+//! ```
+//!
+//! ## Addition assignment
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! #
+//! operator!(Left, Right: a += b {
+//!     // `a` is bound to `&mut self` here, and there is no return type.
+//!     a.0 += b.0;
+//! });
+//!
+//! fn main() {
+//!     let mut left = Left(1);
+//!     left += Right(2);
+//!     assert_eq!(left.0, 3);
+//! }
+//! ```
+//!
+//! ## Subtraction assignment
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! #
+//! operator!(Left, Right: a -= b {
+//!     a.0 -= b.0;
+//! });
+//!
+//! fn main() {
+//!     let mut left = Left(5);
+//!     left -= Right(2);
+//!     assert_eq!(left.0, 3);
+//! }
+//! ```
+//!
+//! ## Multiplication assignment
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! #
+//! operator!(Left, Right: a *= b {
+//!     a.0 *= b.0;
+//! });
+//!
+//! fn main() {
+//!     let mut left = Left(3);
+//!     left *= Right(2);
+//!     assert_eq!(left.0, 6);
+//! }
+//! ```
+//!
+//! ## Division assignment
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! #
+//! operator!(Left, Right: a /= b {
+//!     a.0 /= b.0;
+//! });
+//!
+//! fn main() {
+//!     let mut left = Left(8);
+//!     left /= Right(2);
+//!     assert_eq!(left.0, 4);
+//! }
+//! ```
+//!
+//! ## Remainder assignment
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! #
+//! operator!(Left, Right: a %= b {
+//!     a.0 %= b.0;
+//! });
+//!
+//! fn main() {
+//!     let mut left = Left(9);
+//!     left %= Right(5);
+//!     assert_eq!(left.0, 4);
+//! }
+//! ```
+//!
+//! ## Bitwise AND assignment
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! #
+//! operator!(Left, Right: a &= b {
+//!     a.0 &= b.0;
+//! });
+//!
+//! fn main() {
+//!     let mut left = Left(5);
+//!     left &= Right(6);
+//!     assert_eq!(left.0, 4);
+//! }
+//! ```
+//!
+//! ## Bitwise OR assignment
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! #
+//! operator!(Left, Right: a |= b {
+//!     a.0 |= b.0;
+//! });
+//!
+//! fn main() {
+//!     let mut left = Left(5);
+//!     left |= Right(6);
+//!     assert_eq!(left.0, 7);
+//! }
+//! ```
+//!
+//! ## Bitwise XOR assignment
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! #
+//! operator!(Left, Right: a ^= b {
+//!     a.0 ^= b.0;
+//! });
+//!
+//! fn main() {
+//!     let mut left = Left(5);
+//!     left ^= Right(6);
+//!     assert_eq!(left.0, 3);
+//! }
+//! ```
+//!
+//! ## Shift-left assignment
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! #
+//! operator!(Left, Right: a <<= b {
+//!     a.0 <<= b.0;
+//! });
+//!
+//! fn main() {
+//!     let mut left = Left(5);
+//!     left <<= Right(3);
+//!     assert_eq!(left.0, 40);
+//! }
+//! ```
+//!
+//! ## Shift-right assignment
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! #
+//! operator!(Left, Right: a >>= b {
+//!     a.0 >>= b.0;
+//! });
+//!
+//! fn main() {
+//!     let mut left = Left(43);
+//!     left >>= Right(3);
+//!     assert_eq!(left.0, 5);
+//! }
+//! ```
+//!
+//! ## Equality (`==`)
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! #
+//! operator!(Left, Right: a == b -> bool {
+//!     // Both `a` and `b` are references here.
+//!     a.0 == b.0
+//! });
+//!
+//! fn main() {
+//!     assert!(Left(1) == Right(1));
+//!     assert!(Left(1) != Right(2));
+//! }
+//! ```
+//!
+//! The `-> bool` is optional for this operator.
+//! `ne` can also be overridden by appending a `!=` body with its own `fn` attributes:
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! #
+//! operator!(Left, Right: a == b {
+//!     a.0 == b.0
+//! } #[inline] a != b {
+//!     a.0 != b.0
+//! });
+//!
+//! fn main() {
+//!     assert!(Left(1) == Right(1));
+//!     assert!(Left(1) != Right(2));
+//! }
+//! ```
+//!
+//! Prefix the invocation with `eq` to also implement `Eq`.
+//! The left and right types must be the same type.
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug)] struct Name(String);
+//!
+//! operator!(eq Name, Name: a == b {
+//!     a.0.eq_ignore_ascii_case(&b.0)
+//! });
+//!
+//! fn main() {
+//!     fn assert_eq<T: Eq>(a: T, b: T) { assert!(a == b); }
+//!     assert_eq(Name("foo".to_string()), Name("FOO".to_string()));
+//! }
+//! ```
+//!
+//! ## Partial ordering (`<=>`)
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug)] struct Right(i32);
+//! use core::cmp::Ordering;
+//!
+//! operator!(Left, Right: a == b {
+//!     a.0 == b.0
+//! });
+//!
+//! operator!(Left, Right: a <=> b -> Option<Ordering> {
+//!     // Both `a` and `b` are references here.
+//!     a.0.partial_cmp(&b.0)
+//! });
+//!
+//! fn main() {
+//!     assert!(Left(1) < Right(2));
+//!     assert!(Left(3) >= Right(2));
+//! }
+//! ```
+//!
+//! Generics are passed to both traits as usual:
+//! ```
+//! # use operator_sugar::*;
+//! use core::cmp::Ordering;
+//! use core::marker::PhantomData;
+//!
+//! trait Clock {}
+//! struct Utc;
+//! impl Clock for Utc {}
+//!
+//! struct Timestamp<C: Clock>(u64, PhantomData<C>);
+//!
+//! operator!({C: Clock} Timestamp<C>, u64: a == b {
+//!     a.0 == *b
+//! });
+//!
+//! operator!({C: Clock} Timestamp<C>, u64: a <=> b -> Option<Ordering> {
+//!     a.0.partial_cmp(b)
+//! });
+//!
+//! fn main() {
+//!     assert!(Timestamp::<Utc>(1, PhantomData) < 2);
+//! }
+//! ```
+//!
+//! ## Total ordering (`<=>`)
+//! If the return type is not an `Option`, `Ord` is implemented instead.
+//! Only `Option<..>`, `core::option::Option<..>` and `std::option::Option<..>` are recognized as
+//! an `Option`; other paths to it, such as type aliases, select `Ord`.
+//! The left and right types must be the same type.
+//! `Ord` requires `Eq` and `PartialOrd`, which are not implemented by default.
+//! ```
+//! # use operator_sugar::*;
+//! use core::cmp::Ordering;
+//!
+//! #[derive(Debug, PartialEq, Eq)]
+//! struct Task { priority: u32 }
+//!
+//! operator!(Task, Task: a <=> b -> Ordering {
+//!     b.priority.cmp(&a.priority)
+//! });
+//!
+//! operator!(Task, Task: a <=> b -> core::option::Option<Ordering> {
+//!     Some(a.cmp(b))
+//! });
+//!
+//! fn main() {
+//!     let tasks = [Task { priority: 1 }, Task { priority: 3 }, Task { priority: 2 }];
+//!     assert_eq!(tasks.iter().min(), Some(&Task { priority: 3 }));
+//! }
+//! ```
+//!
+//! Append `+ partialord` to also implement `PartialOrd` by delegating to `Ord`:
+//! ```
+//! # use operator_sugar::*;
+//! use core::cmp::Ordering;
+//!
+//! #[derive(Debug, PartialEq, Eq)]
+//! struct Task { priority: u32 }
+//!
+//! operator!(Task, Task: a <=> b -> Ordering {
+//!     b.priority.cmp(&a.priority)
+//! } + partialord);
+//!
+//! fn main() {
+//!     assert!(Task { priority: 3 } < Task { priority: 1 });
+//! }
+//! ```
+//!
+//! Append `+ minmax` to also add the inherent methods `min_with` and `max_with`, which return the
+//! smaller or larger of two values. The methods are private unless a visibility is written before
+//! the flag, as in `+ pub minmax`, and they get the attributes of the `cmp` method, including the
+//! `inline` and `must_use` prefixes. Flags can be combined:
+//! ```
+//! # use operator_sugar::*;
+//! use core::cmp::Ordering;
+//!
+//! #[derive(Debug, PartialEq, Eq)]
+//! struct Task { priority: u32 }
+//!
+//! operator!(Task, Task: a <=> b -> Ordering {
+//!     b.priority.cmp(&a.priority)
+//! } + partialord + pub(crate) minmax);
+//!
+//! fn main() {
+//!     let urgent = Task { priority: 1 }.min_with(Task { priority: 3 });
+//!     assert_eq!(urgent, Task { priority: 3 });
+//!     assert_eq!(urgent.max_with(Task { priority: 2 }), Task { priority: 2 });
+//! }
+//! ```
+//!
+//! Prefix the invocation with `by_key` to implement `PartialEq`, `Eq`, `PartialOrd` and `Ord`
+//! by comparing a key instead.
+//! The key expression is written in terms of `a`, and is evaluated with `a` bound to each operand
+//! in turn. It must not move out of `a`, so non-`Copy` fields should be borrowed.
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug)]
+//! struct Task { name: String, deadline: u32 }
+//!
+//! operator!(by_key Task: a <=> b {
+//!     (a.deadline, &a.name)
+//! });
+//!
+//! fn main() {
+//!     let mut tasks = vec![
+//!         Task { name: "b".to_string(), deadline: 2 },
+//!         Task { name: "c".to_string(), deadline: 1 },
+//!         Task { name: "a".to_string(), deadline: 2 },
+//!     ];
+//!     tasks.sort();
+//!     let names: Vec<_> = tasks.iter().map(|task| &task.name[..]).collect();
+//!     assert_eq!(names, vec!["c", "a", "b"]);
+//! }
+//! ```
+//!
+//! ## Index
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug)] struct Left(Vec<i32>);
+//! #[derive(Debug)] struct Right(usize);
+//!
+//! operator!(Left, Right: a[b] -> &i32 {
+//!     // The & is required to remind developers that a reference is to be returned.
+//!     &a.0[b.0]
+//! });
+//!
+//! fn main() {
+//!     // We check for 6 not &6, because while the impl returns &6, the [] operator from Rust dereferences it.
+//!     assert_eq!(Left(vec![5, 6, 7])[Right(1)], 6);
+//! }
+//! ```
+//!
+//! The index type can be a reference, such as `&str`:
+//! ```
+//! # use operator_sugar::*;
+//! use std::collections::HashMap;
+//! struct StringMap(HashMap<String, i32>);
+//!
+//! operator!(StringMap, &str: a[b] -> &i32 {
+//!     &a.0[b]
+//! });
+//!
+//! fn main() {
+//!     let map = StringMap(vec![("foo".to_string(), 1)].into_iter().collect());
+//!     let key = String::from("foo");
+//!     assert_eq!(map[&key[..]], 1);
+//!     assert_eq!(map["foo"], 1);
+//! }
+//! ```
+//!
+//! The output type can be unsized, such as a slice:
+//! ```
+//! # use operator_sugar::*;
+//! use core::ops::Range;
+//! struct Buffer(Vec<u8>);
+//!
+//! operator!(Buffer, Range<usize>: a[b] -> &[u8] {
+//!     &a.0[b]
+//! });
+//!
+//! fn main() {
+//!     let buffer = Buffer(vec![1, 2, 3, 4, 5, 6]);
+//!     assert_eq!(&buffer[2..5], &[3, 4, 5]);
+//! }
+//! ```
+//!
+//! Multiple indices can be passed as a tuple:
+//! ```
+//! # use operator_sugar::*;
+//! struct Grid { width: usize, cells: Vec<char> }
+//!
+//! operator!(Grid, (usize, usize): a[b] -> &char {
+//!     &a.cells[b.1 * a.width + b.0]
+//! });
+//!
+//! fn main() {
+//!     let grid = Grid { width: 2, cells: vec!['a', 'b', 'c', 'd'] };
+//!     assert_eq!(grid[(1, 0)], 'b');
+//!     assert_eq!(grid[(0, 1)], 'c');
+//! }
+//! ```
+//!
+//! ## Mutable index
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug)] struct Left(Vec<i32>);
+//! #[derive(Debug)] struct Right(usize);
+//!
+//! operator!(Left, Right: a[b] -> &i32 {
+//!     &a.0[b.0]
+//! });
+//!
+//! operator!(Left, Right: a[b] -> &mut i32 {
+//!     // `a` is bound to `&mut self` here.
+//!     &mut a.0[b.0]
+//! });
+//!
+//! fn main() {
+//!     let mut left = Left(vec![5, 6, 7]);
+//!     left[Right(1)] = 8;
+//!     assert_eq!(left.0, vec![5, 8, 7]);
+//! }
+//! ```
+//!
+//! Both impls can be declared in the same invocation by appending a `mut` body:
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug)] struct Left(Vec<i32>);
+//! #[derive(Debug)] struct Right(usize);
+//!
+//! operator!(Left, Right: a[b] -> &i32 {
+//!     &a.0[b.0]
+//! } mut {
+//!     &mut a.0[b.0]
+//! });
+//!
+//! fn main() {
+//!     let mut left = Left(vec![5, 6, 7]);
+//!     left[Right(1)] += 2;
+//!     assert_eq!(left[Right(1)], 8);
+//! }
+//! ```
+//!
+//! If both bodies only borrow the same place expression, prefix the invocation with `derive_mut`
+//! and write the place expression once, without the `&` in the body and the return type:
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug)] struct Left(Vec<i32>);
+//! #[derive(Debug)] struct Right(usize);
+//!
+//! operator!(derive_mut Left, Right: a[b] -> i32 {
+//!     a.0[b.0]
+//! });
+//!
+//! fn main() {
+//!     let mut left = Left(vec![5, 6, 7]);
+//!     left[Right(1)] += 2;
+//!     assert_eq!(left[Right(1)], 8);
+//! }
+//! ```
+//!
+//! ## Negative (`-`)
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
+//!
+//! operator!(Left: -a -> Answer {
+//!     Answer(-a.0)
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(-Left(43), Answer(-43));
+//! }
+//! ```
+//!
+//! The operand type can be a reference, in which case `a` is bound to the reference:
+//! ```
+//! # use operator_sugar::*;
+//! struct Matrix(Vec<i32>);
+//!
+//! operator!(&Matrix: -a -> Matrix {
+//!     Matrix(a.0.iter().map(|x| -x).collect())
+//! });
+//!
+//! fn main() {
+//!     let matrix = Matrix(vec![1, -2]);
+//!     assert_eq!((-&matrix).0, vec![-1, 2]);
+//!     assert_eq!(matrix.0, vec![1, -2]);
+//! }
+//! ```
+//!
+//! The body can build the owned output from the borrowed operand:
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug, Clone, PartialEq)]
+//! struct Decimal { sign: i8, digits: Vec<u8> }
+//!
+//! operator!(&Decimal: -a -> Decimal {
+//!     Decimal { sign: -a.sign, ..a.clone() }
+//! });
+//!
+//! fn main() {
+//!     let decimal = Decimal { sign: 1, digits: vec![4, 2] };
+//!     assert_eq!(-&decimal, Decimal { sign: -1, digits: vec![4, 2] });
+//! }
+//! ```
+//!
+//! ## Not (`!`)
+//! ```
+//! # use operator_sugar::*;
+//! # #[derive(Debug)] struct Left(i32);
+//! # #[derive(Debug, Eq, PartialEq)] struct Answer(i32);
+//!
+//! operator!(Left: !a -> Answer {
+//!     Answer(!a.0)
+//! });
+//!
+//! fn main() {
+//!     assert_eq!(!Left(43), Answer(!43));
+//! }
+//! ```
+//!
+//! Similarly, `a` is bound to the reference if the operand type is a reference:
+//! ```
+//! # use operator_sugar::*;
+//! struct BitSet(Vec<u64>);
+//!
+//! operator!(&BitSet: !a -> BitSet {
+//!     BitSet(a.0.iter().map(|x| !x).collect())
+//! });
+//!
+//! fn main() {
+//!     let set = BitSet(vec![0, !0]);
+//!     assert_eq!((!&set).0, vec![!0, 0]);
+//!     assert_eq!(set.0, vec![0, !0]);
+//! }
+//! ```
+//!
+//! ## Dereference (`*`)
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug)] struct Handle(String);
+//!
+//! operator!(Handle: *a -> &String {
+//!     // The & is required for the same reason as in the Index operator.
+//!     &a.0
+//! });
+//!
+//! fn main() {
+//!     let handle = Handle("foo".to_string());
+//!     assert_eq!(*handle, "foo");
+//!     assert_eq!(handle.len(), 3);
+//! }
+//! ```
+//!
+//! The target type can be a generic parameter:
+//! ```
+//! # use operator_sugar::*;
+//! struct Cached<T> { value: T, hits: usize }
+//!
+//! operator!({T: Clone} Cached<T>: *a -> &T {
+//!     &a.value
+//! });
+//!
+//! fn main() {
+//!     let cached = Cached { value: vec![1, 2], hits: 0 };
+//!     assert_eq!(cached.len(), 2);
+//!     assert_eq!(cached.hits, 0);
+//! }
+//! ```
+//!
+//! ## Mutable dereference (`*mut`)
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug)] struct Handle(Vec<i32>);
+//!
+//! operator!(Handle: *a -> &Vec<i32> {
+//!     &a.0
+//! });
+//!
+//! operator!(Handle: *mut a -> &mut Vec<i32> {
+//!     // `a` is bound to `&mut self` here.
+//!     &mut a.0
+//! });
+//!
+//! fn main() {
+//!     let mut handle = Handle(vec![1]);
+//!     handle.push(2);
+//!     assert_eq!(*handle, vec![1, 2]);
+//! }
+//! ```
+//!
+//! Both impls can be declared at once with `<->` if the body is a single `&mut` expression.
+//! The `Deref` impl uses the same expression borrowed immutably.
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug)] struct Handle(Vec<i32>);
+//!
+//! operator!(Handle: *a <-> &mut Vec<i32> {
+//!     &mut a.0
+//! });
+//!
+//! fn main() {
+//!     let mut handle = Handle(vec![1]);
+//!     handle.push(2);
+//!     assert_eq!(*handle, vec![1, 2]);
+//! }
+//! ```
+//!
+//! Like the index operators, `derive_mut` can be used to write the place expression only once:
+//! ```
+//! # use operator_sugar::*;
+//! #[derive(Debug)] struct Handle(Vec<i32>);
+//!
+//! operator!(derive_mut Handle: *a -> Vec<i32> {
+//!     a.0
+//! });
+//!
+//! fn main() {
+//!     let mut handle = Handle(vec![1]);
+//!     handle.push(2);
+//!     assert_eq!(*handle, vec![1, 2]);
+//! }
+//! ```
+//!
+//! The `deref` prefix is a shorthand for this case that leaves out the `*`, since the operator is
+//! implied. The body must also be a place expression:
+//! ```
+//! # use operator_sugar::*;
+//! struct Handle(Vec<i32>);
+//!
+//! operator!(deref Handle: a -> Vec<i32> { a.0 });
+//!
+//! fn main() {
+//!     let mut handle = Handle(vec![1]);
+//!     handle.push(2);
+//!     assert_eq!(handle.len(), 2);
+//! }
+//! ```
+//!
+//! ## Call (`()`)
+//! This operator requires the `fn_traits` feature and a nightly compiler.
+//! The crate using it must enable `#![feature(fn_traits, unboxed_closures)]`.
+//!
+//! `a` is bound to `&self`, and each argument type after the first type is bound to the
+//! corresponding argument name. `FnMut` and `FnOnce` are implemented by delegating to `Fn`.
+#![cfg_attr(feature = "fn_traits", doc = "```")]
+#![cfg_attr(not(feature = "fn_traits"), doc = "```ignore")]
+//! #![feature(fn_traits, unboxed_closures)]
+//! # use operator_sugar::*;
+//! struct Adder(i32);
+//!
+//! operator!(Adder, i32: a(b) -> i32 {
+//!     a.0 + b
+//! });
+//!
+//! fn main() {
+//!     let adder = Adder(3);
+//!     assert_eq!(adder(4), 7);
+//!     assert_eq!([1, 2].iter().copied().map(adder).collect::<Vec<_>>(), vec![4, 5]);
+//! }
+//! ```
+//!
+//! Prefix the invocation with `mut` to implement `FnMut` instead, binding `a` to `&mut self`.
+#![cfg_attr(feature = "fn_traits", doc = "```")]
+#![cfg_attr(not(feature = "fn_traits"), doc = "```ignore")]
+//! #![feature(fn_traits, unboxed_closures)]
+//! # use operator_sugar::*;
+//! struct Counter(usize);
+//!
+//! operator!(mut Counter: a() -> usize {
+//!     a.0 += 1;
+//!     a.0
+//! });
+//!
+//! fn main() {
+//!     let mut counter = Counter(0);
+//!     counter();
+//!     assert_eq!(counter(), 2);
+//! }
+//! ```
+//!
+//! Prefix the invocation with `move` to implement `FnOnce` only, binding `a` to `self`.
+#![cfg_attr(feature = "fn_traits", doc = "```")]
+#![cfg_attr(not(feature = "fn_traits"), doc = "```ignore")]
+//! #![feature(fn_traits, unboxed_closures)]
+//! # use operator_sugar::*;
+//! struct OneShot(String);
+//!
+//! operator!(move OneShot: a() -> String {
+//!     a.0
+//! });
+//!
+//! fn main() {
+//!     let once = OneShot("foo".to_string());
+//!     assert_eq!(once(), "foo");
+//! }
+//! ```
+//!
+//! As with the other operators, `a` can be named `self` instead:
+#![cfg_attr(feature = "fn_traits", doc = "```")]
+#![cfg_attr(not(feature = "fn_traits"), doc = "```ignore")]
+//! #![feature(fn_traits, unboxed_closures)]
+//! # use operator_sugar::*;
+//! struct Adder(i32);
+//! struct Counter(usize);
+//! struct OneShot(String);
+//!
+//! operator!(Adder, i32: self(b) -> i32 { self.0 + b });
+//! operator!(mut Counter: self() -> usize {
+//!     self.0 += 1;
+//!     self.0
+//! });
+//! operator!(move OneShot: self() -> String { self.0 });
+//!
+//! fn main() {
+//!     assert_eq!(Adder(3)(4), 7);
+//!     let mut counter = Counter(0);
+//!     counter();
+//!     assert_eq!(counter(), 2);
+//!     assert_eq!(OneShot("foo".to_string())(), "foo");
+//! }
+//! ```
+
+#![no_std]
+
+#[cfg(feature = "num-traits")]
+#[doc(hidden)]
+pub use num_traits as __num_traits;
+
+#[cfg(feature = "derive")]
+pub use operator_sugar_derive::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Sub};
+
+/// Implemented only when `Self` and `T` are the same type, for operators that require it.
+#[doc(hidden)]
+pub trait __SameType<T: ?Sized> {}
+
+impl<T: ?Sized> __SameType<T> for T {}
+
+#[macro_export]
+macro_rules! operator {
+    (@strip [$($tokens:tt)*] ,) => {
+        $crate::__operator!($($tokens)*);
+    };
+    (
+        @strip [$($tokens:tt)*]
+        $t0:tt $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt $($rest:tt)+
+    ) => {
+        $crate::operator!(@strip [$($tokens)* $t0 $t1 $t2 $t3 $t4 $t5 $t6 $t7] $($rest)+);
+    };
+    (@strip [$($tokens:tt)*] $token:tt $($rest:tt)*) => {
+        $crate::operator!(@strip [$($tokens)* $token] $($rest)*);
+    };
+    (@strip [$($tokens:tt)*]) => {
+        $crate::__operator!($($tokens)*);
+    };
+
+    ($($tokens:tt)*) => {
+        $crate::operator!(@strip [] $($tokens)*);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __operator {
+    (@op + [$($C:tt)+] $($rest:tt)*) => {
+        $crate::__operator!(@emit Add add [type Output = $($C)+;] [] [-> Self::Output] $($rest)*);
+    };
+    (@op - [$($C:tt)+] $($rest:tt)*) => {
+        $crate::__operator!(@emit Sub sub [type Output = $($C)+;] [] [-> Self::Output] $($rest)*);
+    };
+    (@op * [$($C:tt)+] $($rest:tt)*) => {
+        $crate::__operator!(@emit Mul mul [type Output = $($C)+;] [] [-> Self::Output] $($rest)*);
+    };
+    (@op / [$($C:tt)+] $($rest:tt)*) => {
+        $crate::__operator!(@emit Div div [type Output = $($C)+;] [] [-> Self::Output] $($rest)*);
+    };
+    (@op % [$($C:tt)+] $($rest:tt)*) => {
+        $crate::__operator!(@emit Rem rem [type Output = $($C)+;] [] [-> Self::Output] $($rest)*);
+    };
+    (@op & [$($C:tt)+] $($rest:tt)*) => {
+        $crate::__operator!(@emit BitAnd bitand [type Output = $($C)+;] [] [-> Self::Output] $($rest)*);
+    };
+    (@op | [$($C:tt)+] $($rest:tt)*) => {
+        $crate::__operator!(@emit BitOr bitor [type Output = $($C)+;] [] [-> Self::Output] $($rest)*);
+    };
+    (@op ^ [$($C:tt)+] $($rest:tt)*) => {
+        $crate::__operator!(@emit BitXor bitxor [type Output = $($C)+;] [] [-> Self::Output] $($rest)*);
+    };
+    (@op << [$($C:tt)+] $($rest:tt)*) => {
+        $crate::__operator!(@emit Shl shl [type Output = $($C)+;] [] [-> Self::Output] $($rest)*);
+    };
+    (@op >> [$($C:tt)+] $($rest:tt)*) => {
+        $crate::__operator!(@emit Shr shr [type Output = $($C)+;] [] [-> Self::Output] $($rest)*);
+    };
+    (@op += [] $($rest:tt)*) => {
+        $crate::__operator!(@emit AddAssign add_assign [] [&mut] [] $($rest)*);
+    };
+    (@op -= [] $($rest:tt)*) => {
+        $crate::__operator!(@emit SubAssign sub_assign [] [&mut] [] $($rest)*);
+    };
+    (@op *= [] $($rest:tt)*) => {
+        $crate::__operator!(@emit MulAssign mul_assign [] [&mut] [] $($rest)*);
+    };
+    (@op /= [] $($rest:tt)*) => {
+        $crate::__operator!(@emit DivAssign div_assign [] [&mut] [] $($rest)*);
+    };
+    (@op %= [] $($rest:tt)*) => {
+        $crate::__operator!(@emit RemAssign rem_assign [] [&mut] [] $($rest)*);
+    };
+    (@op &= [] $($rest:tt)*) => {
+        $crate::__operator!(@emit BitAndAssign bitand_assign [] [&mut] [] $($rest)*);
+    };
+    (@op |= [] $($rest:tt)*) => {
+        $crate::__operator!(@emit BitOrAssign bitor_assign [] [&mut] [] $($rest)*);
+    };
+    (@op ^= [] $($rest:tt)*) => {
+        $crate::__operator!(@emit BitXorAssign bitxor_assign [] [&mut] [] $($rest)*);
+    };
+    (@op <<= [] $($rest:tt)*) => {
+        $crate::__operator!(@emit ShlAssign shl_assign [] [&mut] [] $($rest)*);
+    };
+    (@op >>= [] $($rest:tt)*) => {
+        $crate::__operator!(@emit ShrAssign shr_assign [] [&mut] [] $($rest)*);
+    };
+    (@op $op:tt $($rest:tt)*) => {
+        $crate::__operator!(@missing_output $op compile_error!("Unrecognized operator syntax"););
+    };
+
+    (
+        @emit $Trait:ident $method:ident [$($items:tt)*] [$($receiver:tt)*] [$($ret:tt)*]
+        [$($impl_attr:tt)*] [$($generics:tt)*] [$($constness:tt)*] [$($rhs:tt)*] [$($A:tt)*]
+        [$($where:tt)*] [$($fn_attr:tt)*] $a:ident ($($params:tt)*) { $($body:tt)* }
+    ) => {
+        $($impl_attr)*
+        impl $($generics)* $($constness)* ::core::ops::$Trait $($rhs)* for $($A)* $($where)* {
+            $($items)*
+
+            $crate::__operator!(@method [$($fn_attr)*] $method [$($receiver)*] $a $a ($($params)*) [$($ret)*] { $($body)* });
+        }
+    };
+
+    (
+        @method [$($fn_attr:tt)*] $(@extern $abi:literal)? $name:ident [$($receiver:tt)*] self $a:ident
+        ($($params:tt)*) [$($ret:tt)*] { $($body:tt)* }
+    ) => {
+        $($fn_attr)*
+        $(extern $abi)? fn $name($($receiver)* $a $($params)*) $($ret)* {
+            $($body)*
+        }
+    };
+    (
+        @method [$($fn_attr:tt)*] $(@extern $abi:literal)? $name:ident [$($receiver:tt)*] $a:ident $_a:ident
+        ($($params:tt)*) [$($ret:tt)*] { $($body:tt)* }
+    ) => {
+        $($fn_attr)*
+        $(extern $abi)? fn $name($($receiver)* self $($params)*) $($ret)* {
+            let $a = self;
+            $($body)*
+        }
+    };
+
+    (
+        @same_type [$($impl_attr:tt)*] [$($generics:tt)*] [$($where:tt)*] $A:ty, $B:ty
+    ) => {
+        $($impl_attr)*
+        const _: () = {
+            #[allow(dead_code)]
+            fn same_type $($generics)* () $($where)* {
+                fn check<L: ?Sized + $crate::__SameType<R>, R: ?Sized>() {}
+                check::<$A, $B>();
+            }
+        };
+    };
+
+    ($(#[$impl_attr:meta])* < $($rest:tt)*) => {
+        $crate::__operator!(@angle [$(#[$impl_attr])*] [] [] $($rest)*);
+    };
+    (eq $(#[$impl_attr:meta])* < $($rest:tt)*) => {
+        $crate::__operator!(@angle [eq $(#[$impl_attr])*] [] [] $($rest)*);
+    };
+    (mut $(#[$impl_attr:meta])* < $($rest:tt)*) => {
+        $crate::__operator!(@angle [mut $(#[$impl_attr])*] [] [] $($rest)*);
+    };
+    (move $(#[$impl_attr:meta])* < $($rest:tt)*) => {
+        $crate::__operator!(@angle [move $(#[$impl_attr])*] [] [] $($rest)*);
+    };
+    (assign $(#[$impl_attr:meta])* < $($rest:tt)*) => {
+        $crate::__operator!(@angle [assign $(#[$impl_attr])*] [] [] $($rest)*);
+    };
+    (commutative $(#[$impl_attr:meta])* < $($rest:tt)*) => {
+        $crate::__operator!(@angle [commutative $(#[$impl_attr])*] [] [] $($rest)*);
+    };
+    (swap $(#[$impl_attr:meta])* < $($rest:tt)*) => {
+        $crate::__operator!(@angle [swap $(#[$impl_attr])*] [] [] $($rest)*);
+    };
+    (ref $(#[$impl_attr:meta])* < $($rest:tt)*) => {
+        $crate::__operator!(@angle [ref $(#[$impl_attr])*] [] [] $($rest)*);
+    };
+    (ref_clone $(#[$impl_attr:meta])* < $($rest:tt)*) => {
+        $crate::__operator!(@angle [ref_clone $(#[$impl_attr])*] [] [] $($rest)*);
+    };
+    (wrapping $(#[$impl_attr:meta])* < $($rest:tt)*) => {
+        $crate::__operator!(@angle [wrapping $(#[$impl_attr])*] [] [] $($rest)*);
+    };
+    (elementwise $(#[$impl_attr:meta])* < $($rest:tt)*) => {
+        $crate::__operator!(@angle [elementwise $(#[$impl_attr])*] [] [] $($rest)*);
+    };
+    (sub_from_add_neg $(@[fn $($fn_attr:tt)*])? $(#[$impl_attr:meta])* < $($rest:tt)*) => {
+        $crate::__operator!(@angle [sub_from_add_neg $(@[fn $($fn_attr)*])? $(#[$impl_attr])*] [] [] $($rest)*);
+    };
+    (neg_from_sub $(@[fn $($fn_attr:tt)*])? $(#[$impl_attr:meta])* < $($rest:tt)*) => {
+        $crate::__operator!(@angle [neg_from_sub $(@[fn $($fn_attr)*])? $(#[$impl_attr])*] [] [] $($rest)*);
+    };
+    (derive_mut $(#[$impl_attr:meta])* < $($rest:tt)*) => {
+        $crate::__operator!(@angle [derive_mut $(#[$impl_attr])*] [] [] $($rest)*);
+    };
+    (deref $(#[$impl_attr:meta])* < $($rest:tt)*) => {
+        $crate::__operator!(@angle [deref $(#[$impl_attr])*] [] [] $($rest)*);
+    };
+    (by_key $(#[$impl_attr:meta])* < $($rest:tt)*) => {
+        $crate::__operator!(@angle [by_key $(#[$impl_attr])*] [] [] $($rest)*);
+    };
+    (inline $(#[$impl_attr:meta])* < $($rest:tt)*) => {
+        $crate::__operator!(@angle [inline $(#[$impl_attr])*] [] [] $($rest)*);
+    };
+    (must_use $(#[$impl_attr:meta])* < $($rest:tt)*) => {
+        $crate::__operator!(@angle [must_use $(#[$impl_attr])*] [] [] $($rest)*);
+    };
+    (const $(#[$impl_attr:meta])* < $($rest:tt)*) => {
+        $crate::__operator!(@angle [const $(#[$impl_attr])*] [] [] $($rest)*);
+    };
+
+    (scalar_commutative $($rest:tt)*) => {
+        $crate::__operator!(commutative $($rest)*);
+    };
+
+    (for $($rest:tt)+) => {
+        $crate::__operator!(@for [] $($rest)+);
+    };
+    (@for [$($header:tt)*] { $($items:tt)* } with refs) => {
+        $crate::__operator!(@for_refs [] $($header)* { $($items)* });
+    };
+    (@for [$($header:tt)*] { $($items:tt)* }) => {
+        $crate::operators!($($header)* { $($items)* });
+    };
+    (@for [$($header:tt)*] $token:tt $($rest:tt)+) => {
+        $crate::__operator!(@for [$($header)* $token] $($rest)+);
+    };
+    (@for_refs [$($pre:tt)*] inline $($rest:tt)+) => {
+        $crate::__operator!(@for_refs [$($pre)* inline] $($rest)+);
+    };
+    (@for_refs [$($pre:tt)*] must_use $($rest:tt)+) => {
+        $crate::__operator!(@for_refs [$($pre)* must_use] $($rest)+);
+    };
+    (@for_refs [$($pre:tt)*] $($rest:tt)+) => {
+        $crate::operators!($($pre)* ref $($rest)+);
+    };
+
+    (
+        num $(@[fn $($fn_attr:tt)*])? $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty
+        { $($fields:tt)* }
+    ) => {
+        $crate::__operator_num_traits! {
+            $crate::__operator!(@num [$($($fn_attr)*)?] [$(#[$impl_attr])* $({ $($generics)* })? $A] $($fields)*);
+        }
+    };
+    (@num [$($fn_attr:tt)*] [$($prefix:tt)*] $(,)?) => {};
+    (
+        @num [$($fn_attr:tt)*] [$(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty]
+        zero: $zero:expr $(, $($rest:tt)*)?
+    ) => {
+        $(#[$impl_attr])*
+        impl $(< $($generics)* >)? $crate::__num_traits::Zero for $A {
+            $($fn_attr)*
+            fn zero() -> Self {
+                $zero
+            }
+
+            $($fn_attr)*
+            fn is_zero(&self) -> bool {
+                *self == $zero
+            }
+        }
+        $crate::__operator!(@num [$($fn_attr)*] [$(#[$impl_attr])* $({ $($generics)* })? $A] $($($rest)*)?);
+    };
+    (
+        @num [$($fn_attr:tt)*] [$(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty]
+        one: $one:expr $(, $($rest:tt)*)?
+    ) => {
+        $(#[$impl_attr])*
+        impl $(< $($generics)* >)? $crate::__num_traits::One for $A {
+            $($fn_attr)*
+            fn one() -> Self {
+                $one
+            }
+        }
+        $crate::__operator!(@num [$($fn_attr)*] [$(#[$impl_attr])* $({ $($generics)* })? $A] $($($rest)*)?);
+    };
+    (@num [$($fn_attr:tt)*] [$($prefix:tt)*] $field:ident : $($rest:tt)*) => {
+        compile_error!(concat!("Unknown field `", stringify!($field), "` for the `num` prefix"));
+    };
+
+    (wrapping $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty : $($ops:tt)+) => {
+        $crate::__operator!(@wrapping [$(#[$impl_attr])* $({ $($generics)* })? $A :] $($ops)+);
+    };
+    (@wrapping [$($prefix:tt)*]) => {};
+    (@wrapping [$($prefix:tt)*] #[$($fn_attr:tt)*] $($rest:tt)*) => {
+        $crate::__operator!(@wrapping [$($prefix)* #[$($fn_attr)*]] $($rest)*);
+    };
+    (@wrapping [$($prefix:tt)*] + $($rest:tt)*) => {
+        $crate::__operator!($($prefix)* a + b { let mut a = a; a.0 = a.0.wrapping_add(b.0); a });
+        $crate::__operator!(@wrapping [$($prefix)*] $($rest)*);
+    };
+    (@wrapping [$($prefix:tt)*] - $($rest:tt)*) => {
+        $crate::__operator!($($prefix)* a - b { let mut a = a; a.0 = a.0.wrapping_sub(b.0); a });
+        $crate::__operator!(@wrapping [$($prefix)*] $($rest)*);
+    };
+    (@wrapping [$($prefix:tt)*] * $($rest:tt)*) => {
+        $crate::__operator!($($prefix)* a * b { let mut a = a; a.0 = a.0.wrapping_mul(b.0); a });
+        $crate::__operator!(@wrapping [$($prefix)*] $($rest)*);
+    };
+    (@wrapping [$($prefix:tt)*] / $($rest:tt)*) => {
+        $crate::__operator!($($prefix)* a / b { let mut a = a; a.0 = a.0.wrapping_div(b.0); a });
+        $crate::__operator!(@wrapping [$($prefix)*] $($rest)*);
+    };
+    (@wrapping [$($prefix:tt)*] % $($rest:tt)*) => {
+        $crate::__operator!($($prefix)* a % b { let mut a = a; a.0 = a.0.wrapping_rem(b.0); a });
+        $crate::__operator!(@wrapping [$($prefix)*] $($rest)*);
+    };
+    (@wrapping [$($prefix:tt)*] += $($rest:tt)*) => {
+        $crate::__operator!($($prefix)* a += b { a.0 = a.0.wrapping_add(b.0); });
+        $crate::__operator!(@wrapping [$($prefix)*] $($rest)*);
+    };
+    (@wrapping [$($prefix:tt)*] -= $($rest:tt)*) => {
+        $crate::__operator!($($prefix)* a -= b { a.0 = a.0.wrapping_sub(b.0); });
+        $crate::__operator!(@wrapping [$($prefix)*] $($rest)*);
+    };
+    (@wrapping [$($prefix:tt)*] *= $($rest:tt)*) => {
+        $crate::__operator!($($prefix)* a *= b { a.0 = a.0.wrapping_mul(b.0); });
+        $crate::__operator!(@wrapping [$($prefix)*] $($rest)*);
+    };
+    (@wrapping [$($prefix:tt)*] /= $($rest:tt)*) => {
+        $crate::__operator!($($prefix)* a /= b { a.0 = a.0.wrapping_div(b.0); });
+        $crate::__operator!(@wrapping [$($prefix)*] $($rest)*);
+    };
+    (@wrapping [$($prefix:tt)*] %= $($rest:tt)*) => {
+        $crate::__operator!($($prefix)* a %= b { a.0 = a.0.wrapping_rem(b.0); });
+        $crate::__operator!(@wrapping [$($prefix)*] $($rest)*);
+    };
+    (@wrapping [$($prefix:tt)*] $op:tt $($rest:tt)*) => {
+        compile_error!(concat!("The `", stringify!($op), "` operator has no wrapping variant"));
+    };
+
+    (elementwise $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty : $($ops:tt)+) => {
+        $crate::__operator!(@elementwise [$(#[$impl_attr])* $({ $($generics)* })? $A :] $($ops)+);
+    };
+    (@elementwise [$($prefix:tt)*]) => {};
+    (@elementwise [$($prefix:tt)*] #[$($fn_attr:tt)*] $($rest:tt)*) => {
+        $crate::__operator!(@elementwise [$($prefix)* #[$($fn_attr)*]] $($rest)*);
+    };
+    (@elementwise [$($prefix:tt)*] + $($rest:tt)*) => { $crate::__operator!(@elementwise_binary [$($prefix)*] + $($rest)*); };
+    (@elementwise [$($prefix:tt)*] - $($rest:tt)*) => { $crate::__operator!(@elementwise_binary [$($prefix)*] - $($rest)*); };
+    (@elementwise [$($prefix:tt)*] * $($rest:tt)*) => { $crate::__operator!(@elementwise_binary [$($prefix)*] * $($rest)*); };
+    (@elementwise [$($prefix:tt)*] / $($rest:tt)*) => { $crate::__operator!(@elementwise_binary [$($prefix)*] / $($rest)*); };
+    (@elementwise [$($prefix:tt)*] % $($rest:tt)*) => { $crate::__operator!(@elementwise_binary [$($prefix)*] % $($rest)*); };
+    (@elementwise [$($prefix:tt)*] & $($rest:tt)*) => { $crate::__operator!(@elementwise_binary [$($prefix)*] & $($rest)*); };
+    (@elementwise [$($prefix:tt)*] | $($rest:tt)*) => { $crate::__operator!(@elementwise_binary [$($prefix)*] | $($rest)*); };
+    (@elementwise [$($prefix:tt)*] ^ $($rest:tt)*) => { $crate::__operator!(@elementwise_binary [$($prefix)*] ^ $($rest)*); };
+    (@elementwise [$($prefix:tt)*] += $($rest:tt)*) => { $crate::__operator!(@elementwise_assign [$($prefix)*] += $($rest)*); };
+    (@elementwise [$($prefix:tt)*] -= $($rest:tt)*) => { $crate::__operator!(@elementwise_assign [$($prefix)*] -= $($rest)*); };
+    (@elementwise [$($prefix:tt)*] *= $($rest:tt)*) => { $crate::__operator!(@elementwise_assign [$($prefix)*] *= $($rest)*); };
+    (@elementwise [$($prefix:tt)*] /= $($rest:tt)*) => { $crate::__operator!(@elementwise_assign [$($prefix)*] /= $($rest)*); };
+    (@elementwise [$($prefix:tt)*] %= $($rest:tt)*) => { $crate::__operator!(@elementwise_assign [$($prefix)*] %= $($rest)*); };
+    (@elementwise [$($prefix:tt)*] &= $($rest:tt)*) => { $crate::__operator!(@elementwise_assign [$($prefix)*] &= $($rest)*); };
+    (@elementwise [$($prefix:tt)*] |= $($rest:tt)*) => { $crate::__operator!(@elementwise_assign [$($prefix)*] |= $($rest)*); };
+    (@elementwise [$($prefix:tt)*] ^= $($rest:tt)*) => { $crate::__operator!(@elementwise_assign [$($prefix)*] ^= $($rest)*); };
+    (@elementwise [$($prefix:tt)*] $op:tt $($rest:tt)*) => {
+        compile_error!(concat!("The `", stringify!($op), "` operator cannot be applied elementwise"));
+    };
+    (@elementwise_binary [$($prefix:tt)*] $op:tt $($rest:tt)*) => {
+        $crate::__operator!($($prefix)* a $op b {
+            let mut a = a;
+            for (x, y) in a.0.iter_mut().zip(::core::iter::IntoIterator::into_iter(b.0)) {
+                *x = *x $op y;
+            }
+            a
+        });
+        $crate::__operator!(@elementwise [$($prefix)*] $($rest)*);
+    };
+    (@elementwise_assign [$($prefix:tt)*] $op:tt $($rest:tt)*) => {
+        $crate::__operator!($($prefix)* a $op b {
+            for (x, y) in a.0.iter_mut().zip(::core::iter::IntoIterator::into_iter(b.0)) {
+                *x $op y;
+            }
+        });
+        $crate::__operator!(@elementwise [$($prefix)*] $($rest)*);
+    };
+
+    (
+        sub_from_add_neg $(@[fn $($fn_attr:tt)*])? $(#[$impl_attr:meta])* $({ $($generics:tt)* })?
+        $A:ty
+    ) => {
+        $crate::__operator!($(#[$impl_attr])* $({ $($generics)* })? $A, $A : $($($fn_attr)*)? a - b -> $A
+            where { $A: ::core::ops::Add<Output = $A> + ::core::ops::Neg<Output = $A> }
+            { a + -b });
+    };
+
+    (
+        neg_from_sub $(@[fn $($fn_attr:tt)*])? $(#[$impl_attr:meta])* $({ $($generics:tt)* })?
+        $A:ty { zero: $zero:expr $(,)? }
+    ) => {
+        $crate::__operator!($(#[$impl_attr])* $({ $($generics)* })? $A : $($($fn_attr)*)? -a -> $A
+            where { $A: ::core::ops::Sub<Output = $A> }
+            { $zero - a });
+    };
+
+    (
+        $(@[$constness:ident])? $(#[$impl_attr:meta])* $({ $($generics:tt)* })?
+        $A:ty, [$B:ty, $($Bs:ty),+ $(,)?] : $($rest:tt)*
+    ) => {
+        $crate::__operator!(@rhs_list [$(@[$constness])? $(#[$impl_attr])* $({ $($generics)* })? $A]
+            [$B, $($Bs),+] : $($rest)*);
+    };
+    (
+        commutative $(#[$impl_attr:meta])* $({ $($generics:tt)* })?
+        $A:ty, [$B:ty, $($Bs:ty),+ $(,)?] : $($rest:tt)*
+    ) => {
+        $crate::__operator!(@rhs_list [commutative $(#[$impl_attr])* $({ $($generics)* })? $A]
+            [$B, $($Bs),+] : $($rest)*);
+    };
+    (
+        swap $(#[$impl_attr:meta])* $({ $($generics:tt)* })?
+        $A:ty, [$B:ty, $($Bs:ty),+ $(,)?] : $($rest:tt)*
+    ) => {
+        $crate::__operator!(@rhs_list [swap $(#[$impl_attr])* $({ $($generics)* })? $A]
+            [$B, $($Bs),+] : $($rest)*);
+    };
+    (
+        ref $(#[$impl_attr:meta])* $({ $($generics:tt)* })?
+        $A:ty, [$B:ty, $($Bs:ty),+ $(,)?] : $($rest:tt)*
+    ) => {
+        $crate::__operator!(@rhs_list [ref $(#[$impl_attr])* $({ $($generics)* })? $A]
+            [$B, $($Bs),+] : $($rest)*);
+    };
+    (
+        $(@[$constness:ident])? $(#[$impl_attr:meta])* $({ $($generics:tt)* })?
+        $A:ty, {$($B:ty),+ $(,)?} : $($rest:tt)*
+    ) => {
+        $crate::__operator!(@rhs_list [$(@[$constness])? $(#[$impl_attr])* $({ $($generics)* })? $A]
+            [$($B),+] : $($rest)*);
+    };
+    (
+        commutative $(#[$impl_attr:meta])* $({ $($generics:tt)* })?
+        $A:ty, {$($B:ty),+ $(,)?} : $($rest:tt)*
+    ) => {
+        $crate::__operator!(@rhs_list [commutative $(#[$impl_attr])* $({ $($generics)* })? $A]
+            [$($B),+] : $($rest)*);
+    };
+    (
+        swap $(#[$impl_attr:meta])* $({ $($generics:tt)* })?
+        $A:ty, {$($B:ty),+ $(,)?} : $($rest:tt)*
+    ) => {
+        $crate::__operator!(@rhs_list [swap $(#[$impl_attr])* $({ $($generics)* })? $A]
+            [$($B),+] : $($rest)*);
+    };
+    (
+        ref $(#[$impl_attr:meta])* $({ $($generics:tt)* })?
+        $A:ty, {$($B:ty),+ $(,)?} : $($rest:tt)*
+    ) => {
+        $crate::__operator!(@rhs_list [ref $(#[$impl_attr])* $({ $($generics)* })? $A]
+            [$($B),+] : $($rest)*);
+    };
+
+    (@rhs_list [$($prefix:tt)*] [] : $($rest:tt)*) => {};
+    (@rhs_list [$($prefix:tt)*] [$B:ty $(, $Bs:ty)*] : $($rest:tt)*) => {
+        $crate::__operator!($($prefix)*, $B : $($rest)*);
+        $crate::__operator!(@rhs_list [$($prefix)*] [$($Bs),*] : $($rest)*);
+    };
+
+    (@angle [$($prefix:tt)*] [$($generics:tt)*] [] > $($rest:tt)*) => {
+        $crate::__operator!($($prefix)* { $($generics)* } $($rest)*);
+    };
+    (@angle [$($prefix:tt)*] [$($generics:tt)*] [@] >> $($rest:tt)*) => {
+        $crate::__operator!($($prefix)* { $($generics)* > } $($rest)*);
+    };
+    (@angle [$($prefix:tt)*] [$($generics:tt)*] [@ @ $($depth:tt)*] >> $($rest:tt)*) => {
+        $crate::__operator!(@angle [$($prefix)*] [$($generics)* >>] [$($depth)*] $($rest)*);
+    };
+    (@angle [$($prefix:tt)*] [$($generics:tt)*] [@ $($depth:tt)*] > $($rest:tt)*) => {
+        $crate::__operator!(@angle [$($prefix)*] [$($generics)* >] [$($depth)*] $($rest)*);
+    };
+    (@angle [$($prefix:tt)*] [$($generics:tt)*] [$($depth:tt)*] < $($rest:tt)*) => {
+        $crate::__operator!(@angle [$($prefix)*] [$($generics)* <] [@ $($depth)*] $($rest)*);
+    };
+    (@angle [$($prefix:tt)*] [$($generics:tt)*] [$($depth:tt)*] << $($rest:tt)*) => {
+        $crate::__operator!(@angle [$($prefix)*] [$($generics)* <<] [@ @ $($depth)*] $($rest)*);
+    };
+    (@angle [$($prefix:tt)*] [$($generics:tt)*] [$($depth:tt)*] $token:tt $($rest:tt)*) => {
+        $crate::__operator!(@angle [$($prefix)*] [$($generics)* $token] [$($depth)*] $($rest)*);
+    };
+
+    (inline $($rest:tt)*) => {
+        $crate::__operator!(@fn_attr [inline] [] [] $($rest)*);
+    };
+
+    (must_use $($rest:tt)*) => {
+        $crate::__operator!(@fn_attr [must_use] [] [] $($rest)*);
+    };
+
+    (const $($rest:tt)*) => {
+        $crate::__operator_const_ops! {
+            $crate::__operator!(@[const] $($rest)*);
+        }
+    };
+
+    (@fn_attr [$($attr:tt)*] [$($pre:tt)*] [] : $($rest:tt)*) => {
+        $crate::__operator!($($pre)* : #[$($attr)*] $($rest)*);
+    };
+    (@fn_attr [$($attr:tt)*] [$($pre:tt)*] [$($depth:tt)*] < $($rest:tt)*) => {
+        $crate::__operator!(@fn_attr [$($attr)*] [$($pre)* <] [@ $($depth)*] $($rest)*);
+    };
+    (@fn_attr [$($attr:tt)*] [$($pre:tt)*] [$($depth:tt)*] << $($rest:tt)*) => {
+        $crate::__operator!(@fn_attr [$($attr)*] [$($pre)* <<] [@ @ $($depth)*] $($rest)*);
+    };
+    (@fn_attr [$($attr:tt)*] [$($pre:tt)*] [@ $($depth:tt)*] > $($rest:tt)*) => {
+        $crate::__operator!(@fn_attr [$($attr)*] [$($pre)* >] [$($depth)*] $($rest)*);
+    };
+    (@fn_attr [$($attr:tt)*] [$($pre:tt)*] [@ @ $($depth:tt)*] >> $($rest:tt)*) => {
+        $crate::__operator!(@fn_attr [$($attr)*] [$($pre)* >>] [$($depth)*] $($rest)*);
+    };
+    (@fn_attr [$($attr:tt)*] [$($pre:tt)*] [$($depth:tt)*] $token:tt $($rest:tt)*) => {
+        $crate::__operator!(@fn_attr [$($attr)*] [$($pre)* $token] [$($depth)*] $($rest)*);
+    };
+    (@fn_attr [$($attr:tt)*] [$($pre:tt)*] [$($depth:tt)*]) => {
+        $crate::__operator!(@fn_attr_keyword [#[$($attr)*]] [] $($pre)*);
+    };
+
+    (@fn_attr_keyword [$($attr:tt)*] [$($pre:tt)*] inline $($rest:tt)*) => {
+        $crate::__operator!(@fn_attr_keyword [$($attr)*] [$($pre)* inline] $($rest)*);
+    };
+    (@fn_attr_keyword [$($attr:tt)*] [$($pre:tt)*] must_use $($rest:tt)*) => {
+        $crate::__operator!(@fn_attr_keyword [$($attr)*] [$($pre)* must_use] $($rest)*);
+    };
+    (@fn_attr_keyword [$($attr:tt)*] [$($pre:tt)*] $keyword:ident @[fn $($fn_attr:tt)*] $($rest:tt)*) => {
+        $crate::__operator!(@fn_attr_keyword [$($fn_attr)* $($attr)*] [$($pre)*] $keyword $($rest)*);
+    };
+    (@fn_attr_keyword [$($attr:tt)*] [$($pre:tt)*] sub_from_add_neg $($rest:tt)*) => {
+        $crate::__operator!($($pre)* sub_from_add_neg @[fn $($attr)*] $($rest)*);
+    };
+    (@fn_attr_keyword [$($attr:tt)*] [$($pre:tt)*] neg_from_sub $($rest:tt)*) => {
+        $crate::__operator!($($pre)* neg_from_sub @[fn $($attr)*] $($rest)*);
+    };
+    (@fn_attr_keyword [$($attr:tt)*] [$($pre:tt)*] num $($rest:tt)*) => {
+        $crate::__operator!($($pre)* num @[fn $($attr)*] $($rest)*);
+    };
+    (@fn_attr_keyword [$($attr:tt)*] [$($pre:tt)*] $($rest:tt)*) => {
+        compile_error!("Unrecognized operator syntax");
+    };
+
+    (
+        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* $a:ident == $b:ident $(-> bool)?
+        $(where { $($where:tt)* })?
+        { $($body:tt)* }
+        $($(#[$ne_attr:meta])* $c:ident != $d:ident $(-> bool)? { $($ne_body:tt)* })?
+    ) => {
+        $(#[$impl_attr])*
+        impl $(< $($generics)* >)? ::core::cmp::PartialEq<$B> for $A $(where $($where)*)? {
+            $crate::__operator!(@method [$(#[$fn_attr])*] eq [&] $a $a (, $b: &$B) [-> bool] { $($body)* });
+
+            $(
+                $crate::__operator!(@method [$(#[$ne_attr])*] ne [&] $c $c (, $d: &$B) [-> bool] { $($ne_body)* });
+            )?
+        }
+    };
+
+    (
+        eq $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* $a:ident == $b:ident $(-> bool)?
+        $(where { $($where:tt)* })?
+        { $($body:tt)* } $($rest:tt)*
+    ) => {
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A, $B :
+            $(#[$fn_attr])* $a == $b $(where { $($where)* })? { $($body)* } $($rest)*);
+
+        $crate::__operator!(@same_type [$(#[$impl_attr])*] [$(< $($generics)* >)?] [$(where $($where)*)?] $A, $B);
+
+        $(#[$impl_attr])*
+        impl $(< $($generics)* >)? ::core::cmp::Eq for $A $(where $($where)*)? {}
+    };
+
+    (
+        $(@[$constness:ident])? $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* $a:ident $op:tt $b:ident $(-> $C:ty)?
+        $(where { $($where:tt)* })?
+        { $($body:tt)* }
+    ) => {
+        $crate::__operator!(@op $op [$($C)?]
+            [$(#[$impl_attr])*] [$(< $($generics)* >)?] [$($constness)?] [<$B>] [$A]
+            [$(where $($where)*)?] [$(#[$fn_attr])*] $a (, $b: $B) { $($body)* });
+    };
+
+    (
+        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* $a:ident <=> $b:ident -> $(::)? core::option::Option<$O:ty> $($rest:tt)*
+    ) => {
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A, $B :
+            $(#[$fn_attr])* $a <=> $b -> Option<$O> $($rest)*);
+    };
+    (
+        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* $a:ident <=> $b:ident -> $(::)? std::option::Option<$O:ty> $($rest:tt)*
+    ) => {
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A, $B :
+            $(#[$fn_attr])* $a <=> $b -> Option<$O> $($rest)*);
+    };
+
+    (
+        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* $a:ident <=> $b:ident -> Option<$O:ty>
+        $(where { $($where:tt)* })?
+        { $($body:tt)* }
+    ) => {
+        $(#[$impl_attr])*
+        impl $(< $($generics)* >)? ::core::cmp::PartialOrd<$B> for $A $(where $($where)*)? {
+            $crate::__operator!(@method [$(#[$fn_attr])*] partial_cmp [&] $a $a (, $b: &$B) [-> ::core::option::Option<$O>] { $($body)* });
+        }
+    };
+
+    (
+        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* $a:ident <=> $b:ident -> $O:ty
+        $(where { $($where:tt)* })?
+        { $($body:tt)* }
+    ) => {
+        $crate::__operator!(@same_type [$(#[$impl_attr])*] [$(< $($generics)* >)?] [$(where $($where)*)?] $A, $B);
+
+        $(#[$impl_attr])*
+        impl $(< $($generics)* >)? ::core::cmp::Ord for $A $(where $($where)*)? {
+            $crate::__operator!(@method [$(#[$fn_attr])*] cmp [&] $a $a (, $b: &Self) [-> $O] { $($body)* });
+        }
+    };
+
+    (
+        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* $a:ident <=> $b:ident -> $O:ty
+        $(where { $($where:tt)* })?
+        { $($body:tt)* } + pub $(($($restrict:tt)*))? $flag:ident $($flags:tt)*
+    ) => {
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A, $B :
+            $(#[$fn_attr])* $a <=> $b -> $O $(where { $($where)* })? { $($body)* } $($flags)*);
+        $crate::__operator!(@ord_flag $flag [pub $(($($restrict)*))?] [$(#[$fn_attr])*]
+            $(#[$impl_attr])* $({ $($generics)* })? $A $(where { $($where)* })?);
+    };
+
+    (
+        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* $a:ident <=> $b:ident -> $O:ty
+        $(where { $($where:tt)* })?
+        { $($body:tt)* } + $flag:ident $($flags:tt)*
+    ) => {
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A, $B :
+            $(#[$fn_attr])* $a <=> $b -> $O $(where { $($where)* })? { $($body)* } $($flags)*);
+        $crate::__operator!(@ord_flag $flag [] [$(#[$fn_attr])*]
+            $(#[$impl_attr])* $({ $($generics)* })? $A $(where { $($where)* })?);
+    };
+
+    (
+        by_key $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* self $($rest:tt)*
+    ) => {
+        compile_error!("The operand cannot be named `self` with `by_key`, because the key is computed for both operands");
+    };
+
+    (
+        by_key $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* $a:ident <=> $b:ident
+        $(where { $($where:tt)* })?
+        { $key:expr }
+    ) => {
+        $(#[$impl_attr])*
+        impl $(< $($generics)* >)? ::core::cmp::PartialEq for $A $(where $($where)*)? {
+            $(#[$fn_attr])*
+            fn eq(&self, other: &Self) -> bool {
+                let lhs = { let $a = self; $key };
+                let rhs = { let $a = other; $key };
+                lhs == rhs
+            }
+        }
+
+        $(#[$impl_attr])*
+        impl $(< $($generics)* >)? ::core::cmp::Eq for $A $(where $($where)*)? {}
+
+        $(#[$impl_attr])*
+        impl $(< $($generics)* >)? ::core::cmp::PartialOrd for $A $(where $($where)*)? {
+            $(#[$fn_attr])*
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        $(#[$impl_attr])*
+        impl $(< $($generics)* >)? ::core::cmp::Ord for $A $(where $($where)*)? {
+            $(#[$fn_attr])*
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                let lhs = { let $a = self; $key };
+                let rhs = { let $a = other; $key };
+                ::core::cmp::Ord::cmp(&lhs, &rhs)
+            }
+        }
+    };
+
+    (
+        @ord_flag partialord [] [$($fn_attr:tt)*]
+        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty
+        $(where { $($where:tt)* })?
+    ) => {
+        $(#[$impl_attr])*
+        impl $(< $($generics)* >)? ::core::cmp::PartialOrd for $A $(where $($where)*)? {
+            $($fn_attr)*
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+    };
+
+    (
+        @ord_flag minmax [$($vis:tt)*] [$($fn_attr:tt)*]
+        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty
+        $(where { $($where:tt)* })?
+    ) => {
+        $(#[$impl_attr])*
+        impl $(< $($generics)* >)? $A $(where $($where)*)? {
+            /// Returns the smaller of the two values by their `Ord` implementation.
+            $($fn_attr)*
+            $($vis)* fn min_with(self, other: Self) -> Self {
+                ::core::cmp::Ord::min(self, other)
+            }
+
+            /// Returns the larger of the two values by their `Ord` implementation.
+            $($fn_attr)*
+            $($vis)* fn max_with(self, other: Self) -> Self {
+                ::core::cmp::Ord::max(self, other)
+            }
+        }
+    };
+
+    (@ord_flag partialord [$($vis:tt)+] $($rest:tt)*) => {
+        compile_error!("The `partialord` flag implements a trait and does not take a visibility");
+    };
+    (@ord_flag $flag:ident $($rest:tt)*) => {
+        compile_error!(concat!("Unknown flag `", stringify!($flag), "` for the `<=>` operator"));
+    };
+
+    (
+        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* $a:ident[$b:ident] -> &mut $C:ty
+        $(where { $($where:tt)* })?
+        { $($body:tt)* }
+    ) => {
+        $(#[$impl_attr])*
+        impl $(< $($generics)* >)? ::core::ops::IndexMut<$B> for $A $(where $($where)*)? {
+            $crate::__operator!(@method [$(#[$fn_attr])*] index_mut [&mut] $a $a (, $b: $B) [-> &mut $C] { $($body)* });
+        }
+    };
+
     (
         $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
-        $(#[$fn_attr:meta])* $a:ident + $b:ident -> $C:ty
+        $(#[$fn_attr:meta])* $a:ident[$b:ident] -> & $C:ty
+        $(where { $($where:tt)* })?
         { $($body:tt)* }
     ) => {
         $(#[$impl_attr])*
-        impl $(< $($generics)* >)? ::core::ops::Add<$B> for $A {
+        impl $(< $($generics)* >)? ::core::ops::Index<$B> for $A $(where $($where)*)? {
             type Output = $C;
 
-            $(#[$fn_attr])*
-                fn add(self, $b: $B) -> Self::Output {
-                    let $a = self;
-                    $($body)*
+            $crate::__operator!(@method [$(#[$fn_attr])*] index [&] $a $a (, $b: $B) [-> &Self::Output] { $($body)* });
+        }
+    };
+
+    (
+        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* $a:ident[$b:ident] -> & $C:ty
+        $(where { $($where:tt)* })?
+        { $($body:tt)* } mut { $($body_mut:tt)* }
+    ) => {
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A, $B :
+            $(#[$fn_attr])* $a[$b] -> &$C $(where { $($where)* })? { $($body)* });
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A, $B :
+            $(#[$fn_attr])* $a[$b] -> &mut $C $(where { $($where)* })? { $($body_mut)* });
+    };
+
+    (
+        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* $a:ident[$b:ident] -> &mut $C:ty
+        $(where { $($where:tt)* })?
+        { $($body:tt)* } $($rest:tt)+
+    ) => {
+        compile_error!("Unrecognized operator syntax");
+    };
+
+    (
+        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* $a:ident[$b:ident] -> & $C:ty
+        $(where { $($where:tt)* })?
+        { $($body:tt)* } $($rest:tt)+
+    ) => {
+        compile_error!("Unrecognized operator syntax");
+    };
+
+    (
+        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* $a:ident[$b:ident] -> $C:ty
+        $(where { $($where:tt)* })?
+        { $($body:tt)* } $($rest:tt)*
+    ) => {
+        compile_error!("The index operator returns a reference, as in `a[b] -> &Output`");
+    };
+
+    (
+        derive_mut $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* $a:ident[$b:ident] -> $C:ty
+        $(where { $($where:tt)* })?
+        { $place:expr }
+    ) => {
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A, $B :
+            $(#[$fn_attr])* $a[$b] -> &$C $(where { $($where)* })? { &$place } mut { &mut $place });
+    };
+
+    (
+        $(@[$constness:ident])? $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* -$a:ident -> $C:ty
+        $(where { $($where:tt)* })?
+        { $($body:tt)* }
+    ) => {
+        $crate::__operator!(@emit Neg neg [type Output = $C;] [] [-> Self::Output]
+            [$(#[$impl_attr])*] [$(< $($generics)* >)?] [$($constness)?] [] [$A]
+            [$(where $($where)*)?] [$(#[$fn_attr])*] $a () { $($body)* });
+    };
+
+    (
+        $(@[$constness:ident])? $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* !$a:ident -> $C:ty
+        $(where { $($where:tt)* })?
+        { $($body:tt)* }
+    ) => {
+        $crate::__operator!(@emit Not not [type Output = $C;] [] [-> Self::Output]
+            [$(#[$impl_attr])*] [$(< $($generics)* >)?] [$($constness)?] [] [$A]
+            [$(where $($where)*)?] [$(#[$fn_attr])*] $a () { $($body)* });
+    };
+
+    (
+        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* *$a:ident <-> &mut $C:ty
+        $(where { $($where:tt)* })?
+        { &mut $($place:tt)* }
+    ) => {
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A :
+            $(#[$fn_attr])* *$a -> &$C $(where { $($where)* })? { &$($place)* });
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A :
+            $(#[$fn_attr])* *mut $a -> &mut $C $(where { $($where)* })? { &mut $($place)* });
+    };
+
+    (
+        derive_mut $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* *$a:ident -> $C:ty
+        $(where { $($where:tt)* })?
+        { $place:expr }
+    ) => {
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A :
+            $(#[$fn_attr])* *$a <-> &mut $C $(where { $($where)* })? { &mut $place });
+    };
+
+    (
+        deref $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* $a:ident -> $C:ty
+        $(where { $($where:tt)* })?
+        { $place:expr }
+    ) => {
+        $crate::__operator!(
+            derive_mut $(#[$impl_attr])* $({ $($generics)* })? $A :
+            $(#[$fn_attr])* *$a -> $C $(where { $($where)* })? { $place });
+    };
+
+    (
+        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* *mut $a:ident -> &mut $C:ty
+        $(where { $($where:tt)* })?
+        { $($body:tt)* }
+    ) => {
+        $(#[$impl_attr])*
+        impl $(< $($generics)* >)? ::core::ops::DerefMut for $A $(where $($where)*)? {
+            $crate::__operator!(@method [$(#[$fn_attr])*] deref_mut [&mut] $a $a () [-> &mut $C] { $($body)* });
+        }
+    };
+
+    (
+        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* *$a:ident -> & $C:ty
+        $(where { $($where:tt)* })?
+        { $($body:tt)* }
+    ) => {
+        $(#[$impl_attr])*
+        impl $(< $($generics)* >)? ::core::ops::Deref for $A $(where $($where)*)? {
+            type Target = $C;
+
+            $crate::__operator!(@method [$(#[$fn_attr])*] deref [&] $a $a () [-> &Self::Target] { $($body)* });
+        }
+    };
+
+    (
+        move $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty $(, $B:ty)* :
+        $(#[$fn_attr:meta])* $a:ident($($b:ident),*) -> $C:ty
+        $(where { $($where:tt)* })?
+        { $($body:tt)* }
+    ) => {
+        $crate::__operator_fn_traits! {
+            $(#[$impl_attr])*
+            impl $(< $($generics)* >)? ::core::ops::FnOnce<($($B,)*)> for $A $(where $($where)*)? {
+                type Output = $C;
+
+                $crate::__operator!(@method [$(#[$fn_attr])*] @extern "rust-call" call_once [] $a $a
+                    (, ($($b,)*): ($($B,)*)) [-> Self::Output] { $($body)* });
+            }
+        }
+    };
+
+    (
+        mut $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty $(, $B:ty)* :
+        $(#[$fn_attr:meta])* $a:ident($($b:ident),*) -> $C:ty
+        $(where { $($where:tt)* })?
+        { $($body:tt)* }
+    ) => {
+        $crate::__operator_fn_traits! {
+            $(#[$impl_attr])*
+            impl $(< $($generics)* >)? ::core::ops::FnOnce<($($B,)*)> for $A $(where $($where)*)? {
+                type Output = $C;
+
+                extern "rust-call" fn call_once(mut self, args: ($($B,)*)) -> Self::Output {
+                    ::core::ops::FnMut::call_mut(&mut self, args)
+                }
+            }
+
+            $(#[$impl_attr])*
+            impl $(< $($generics)* >)? ::core::ops::FnMut<($($B,)*)> for $A $(where $($where)*)? {
+                $crate::__operator!(@method [$(#[$fn_attr])*] @extern "rust-call" call_mut [&mut] $a $a
+                    (, ($($b,)*): ($($B,)*)) [-> Self::Output] { $($body)* });
+            }
+        }
+    };
+
+    (
+        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty $(, $B:ty)* :
+        $(#[$fn_attr:meta])* $a:ident($($b:ident),*) -> $C:ty
+        $(where { $($where:tt)* })?
+        { $($body:tt)* }
+    ) => {
+        $crate::__operator_fn_traits! {
+            $(#[$impl_attr])*
+            impl $(< $($generics)* >)? ::core::ops::FnOnce<($($B,)*)> for $A $(where $($where)*)? {
+                type Output = $C;
+
+                extern "rust-call" fn call_once(self, args: ($($B,)*)) -> Self::Output {
+                    ::core::ops::Fn::call(&self, args)
+                }
+            }
+
+            $(#[$impl_attr])*
+            impl $(< $($generics)* >)? ::core::ops::FnMut<($($B,)*)> for $A $(where $($where)*)? {
+                extern "rust-call" fn call_mut(&mut self, args: ($($B,)*)) -> Self::Output {
+                    ::core::ops::Fn::call(&*self, args)
                 }
+            }
+
+            $(#[$impl_attr])*
+            impl $(< $($generics)* >)? ::core::ops::Fn<($($B,)*)> for $A $(where $($where)*)? {
+                $crate::__operator!(@method [$(#[$fn_attr])*] @extern "rust-call" call [&] $a $a
+                    (, ($($b,)*): ($($B,)*)) [-> Self::Output] { $($body)* });
+            }
         }
     };
 
     (
-        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
-        $(#[$fn_attr:meta])* $a:ident - $b:ident -> $C:ty
-        { $($body:tt)* }
+        assign $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $($ops:tt)*
     ) => {
-        $(#[$impl_attr])*
-        impl $(< $($generics)* >)? ::core::ops::Sub<$B> for $A {
-            type Output = $C;
+        $crate::__operator!(@assign_ops [$(#[$impl_attr])* $({ $($generics)* })? $A, $B] [] $($ops)*);
+    };
+    (@assign_ops [$($prefix:tt)*] [$($fn_attr:tt)*]) => {};
+    (@assign_ops [$($prefix:tt)*] [$($fn_attr:tt)*] #[$($attr:tt)*] $($rest:tt)*) => {
+        $crate::__operator!(@assign_ops [$($prefix)*] [$($fn_attr)* #[$($attr)*]] $($rest)*);
+    };
+    (@assign_ops [$($prefix:tt)*] [$($fn_attr:tt)*] $op:tt $($rest:tt)*) => {
+        $crate::__operator!(@assign $op [$($fn_attr)*] $($prefix)*);
+        $crate::__operator!(@assign_ops [$($prefix)*] [$($fn_attr)*] $($rest)*);
+    };
 
-            $(#[$fn_attr])*
-                fn sub(self, $b: $B) -> Self::Output {
-                    let $a = self;
-                    $($body)*
-                }
-        }
+    (
+        assign $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $($ops:tt)*
+    ) => {
+        $crate::__operator!(assign $(#[$impl_attr])* $({ $($generics)* })? $A, $A : $($ops)*);
     };
 
     (
         $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
-        $(#[$fn_attr:meta])* $a:ident * $b:ident -> $C:ty
-        { $($body:tt)* }
+        $(#[$fn_attr:meta])* $a:ident $op:tt $b:ident -> $C:ty
+        $(where { $($where:tt)* })?
+        { $($body:tt)* } with assign
     ) => {
-        $(#[$impl_attr])*
-        impl $(< $($generics)* >)? ::core::ops::Mul<$B> for $A {
-            type Output = $C;
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A, $B :
+            $(#[$fn_attr])* $a $op $b -> $C $(where { $($where)* })? { $($body)* });
+        $crate::__operator!(@assign_of $op [$(#[$fn_attr])*]
+            $(#[$impl_attr])* $({ $($generics)* })? $A, $B $(where { $($where)* })?);
+    };
 
-            $(#[$fn_attr])*
-                fn mul(self, $b: $B) -> Self::Output {
-                    let $a = self;
-                    $($body)*
-                }
-        }
+    (
+        swap $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* self $($rest:tt)*
+    ) => {
+        compile_error!("The left operand cannot be named `self` with `swap`, because the swapped implementation takes it as the right operand");
     };
 
     (
-        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
-        $(#[$fn_attr:meta])* $a:ident / $b:ident -> $C:ty
-        { $($body:tt)* }
+        commutative $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* self $($rest:tt)*
     ) => {
-        $(#[$impl_attr])*
-        impl $(< $($generics)* >)? ::core::ops::Div<$B> for $A {
-            type Output = $C;
+        compile_error!("The left operand cannot be named `self` with `commutative`, because the swapped implementation takes it as the right operand");
+    };
 
-            $(#[$fn_attr])*
-                fn div(self, $b: $B) -> Self::Output {
-                    let $a = self;
-                    $($body)*
-                }
-        }
+    (
+        swap $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* $a:ident $op:tt $b:ident -> $C:ty
+        $(where { $($where:tt)* })?
+        { $($body:tt)* }
+    ) => {
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A, $B :
+            $(#[$fn_attr])* $a $op $b -> $C $(where { $($where)* })? { $($body)* });
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $B, $A :
+            $(#[$fn_attr])* $b $op $a -> $C $(where { $($where)* })? { $($body)* });
     };
 
     (
-        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
-        $(#[$fn_attr:meta])* $a:ident % $b:ident -> $C:ty
+        commutative $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* $a:ident $op:tt $b:ident -> $C:ty
+        $(where { $($where:tt)* })?
         { $($body:tt)* }
     ) => {
-        $(#[$impl_attr])*
-        impl $(< $($generics)* >)? ::core::ops::Rem<$B> for $A {
-            type Output = $C;
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A, $B :
+            $(#[$fn_attr])* $a $op $b -> $C $(where { $($where)* })? { $($body)* });
+        $crate::__operator!(@commutative $op
+            $(#[$impl_attr])* $({ $($generics)* })? $B, $A :
+            $(#[$fn_attr])* $b $op $a -> $C $(where { $($where)* })? { $($body)* });
+    };
 
-            $(#[$fn_attr])*
-                fn rem(self, $b: $B) -> Self::Output {
-                    let $a = self;
-                    $($body)*
-                }
-        }
+    (@commutative + $($rest:tt)*) => { $crate::__operator!($($rest)*); };
+    (@commutative * $($rest:tt)*) => { $crate::__operator!($($rest)*); };
+    (@commutative & $($rest:tt)*) => { $crate::__operator!($($rest)*); };
+    (@commutative | $($rest:tt)*) => { $crate::__operator!($($rest)*); };
+    (@commutative ^ $($rest:tt)*) => { $crate::__operator!($($rest)*); };
+    (@commutative $op:tt $($rest:tt)*) => {
+        compile_error!(concat!("The `", stringify!($op), "` operator is not commutative"));
     };
 
     (
-        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
-        $(#[$fn_attr:meta])* $a:ident & $b:ident -> $C:ty
-        { $($body:tt)* }
+        ref $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* $a:ident $op:tt $b:ident -> $C:ty
+        $(where { $($where:tt)* })?
+        { $($body:tt)* } $($rest:tt)*
     ) => {
-        $(#[$impl_attr])*
-        impl $(< $($generics)* >)? ::core::ops::BitAnd<$B> for $A {
-            type Output = $C;
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A, $B :
+            $(#[$fn_attr])* $a $op $b -> $C $(where { $($where)* })? { $($body)* } $($rest)*);
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? &$A, $B :
+            $(#[$fn_attr])* $a $op $b -> $C $(where { $($where)* })? { *$a $op $b });
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A, &$B :
+            $(#[$fn_attr])* $a $op $b -> $C $(where { $($where)* })? { $a $op *$b });
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? &$A, &$B :
+            $(#[$fn_attr])* $a $op $b -> $C $(where { $($where)* })? { *$a $op *$b });
+    };
 
-            $(#[$fn_attr])*
-                fn bitand(self, $b: $B) -> Self::Output {
-                    let $a = self;
-                    $($body)*
-                }
-        }
+    (
+        ref $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* $a:ident $op:tt $b:ident
+        $(where { $($where:tt)* })?
+        { $($body:tt)* } $($rest:tt)*
+    ) => {
+        $crate::__operator!(@ref_op $op
+            [$(#[$impl_attr])* $({ $($generics)* })? $A, $B :
+                $(#[$fn_attr])* $a $op $b $(where { $($where)* })? { $($body)* } $($rest)*]
+            [$(#[$impl_attr])* $({ $($generics)* })? $A, &$B :
+                $(#[$fn_attr])* $a $op $b $(where { $($where)* })? { *$a $op *$b }]);
     };
 
     (
-        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
-        $(#[$fn_attr:meta])* $a:ident | $b:ident -> $C:ty
+        ref $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* -$a:ident -> $C:ty
+        $(where { $($where:tt)* })?
         { $($body:tt)* }
     ) => {
-        $(#[$impl_attr])*
-        impl $(< $($generics)* >)? ::core::ops::BitOr<$B> for $A {
-            type Output = $C;
-
-            $(#[$fn_attr])*
-                fn bitor(self, $b: $B) -> Self::Output {
-                    let $a = self;
-                    $($body)*
-                }
-        }
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A :
+            $(#[$fn_attr])* -$a -> $C $(where { $($where)* })? { $($body)* });
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? &$A :
+            $(#[$fn_attr])* -$a -> $C $(where { $($where)* })? { -*$a });
     };
 
     (
-        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
-        $(#[$fn_attr:meta])* $a:ident ^ $b:ident -> $C:ty
+        ref $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* !$a:ident -> $C:ty
+        $(where { $($where:tt)* })?
         { $($body:tt)* }
     ) => {
-        $(#[$impl_attr])*
-        impl $(< $($generics)* >)? ::core::ops::BitXor<$B> for $A {
-            type Output = $C;
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A :
+            $(#[$fn_attr])* !$a -> $C $(where { $($where)* })? { $($body)* });
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? &$A :
+            $(#[$fn_attr])* !$a -> $C $(where { $($where)* })? { !*$a });
+    };
 
-            $(#[$fn_attr])*
-                fn bitxor(self, $b: $B) -> Self::Output {
-                    let $a = self;
-                    $($body)*
-                }
-        }
+    (
+        ref_clone $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
+        $(#[$fn_attr:meta])* $a:ident $op:tt $b:ident -> $C:ty
+        $(where { $($where:tt)* })?
+        { $($body:tt)* } $($rest:tt)*
+    ) => {
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A, $B :
+            $(#[$fn_attr])* $a $op $b -> $C $(where { $($where)* })? { $($body)* } $($rest)*);
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? &$A, $B :
+            $(#[$fn_attr])* $a $op $b -> $C $(where { $($where)* })? {
+                ::core::clone::Clone::clone($a) $op $b
+            });
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A, &$B :
+            $(#[$fn_attr])* $a $op $b -> $C $(where { $($where)* })? {
+                $a $op ::core::clone::Clone::clone($b)
+            });
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? &$A, &$B :
+            $(#[$fn_attr])* $a $op $b -> $C $(where { $($where)* })? {
+                ::core::clone::Clone::clone($a) $op ::core::clone::Clone::clone($b)
+            });
+    };
+
+    (@ref_op += $($rest:tt)*) => { $crate::__operator!(@ref_both $($rest)*); };
+    (@ref_op -= $($rest:tt)*) => { $crate::__operator!(@ref_both $($rest)*); };
+    (@ref_op *= $($rest:tt)*) => { $crate::__operator!(@ref_both $($rest)*); };
+    (@ref_op /= $($rest:tt)*) => { $crate::__operator!(@ref_both $($rest)*); };
+    (@ref_op %= $($rest:tt)*) => { $crate::__operator!(@ref_both $($rest)*); };
+    (@ref_op &= $($rest:tt)*) => { $crate::__operator!(@ref_both $($rest)*); };
+    (@ref_op |= $($rest:tt)*) => { $crate::__operator!(@ref_both $($rest)*); };
+    (@ref_op ^= $($rest:tt)*) => { $crate::__operator!(@ref_both $($rest)*); };
+    (@ref_op <<= $($rest:tt)*) => { $crate::__operator!(@ref_both $($rest)*); };
+    (@ref_op >>= $($rest:tt)*) => { $crate::__operator!(@ref_both $($rest)*); };
+    (@ref_op == [$($value:tt)*] [$($borrowed:tt)*]) => { $crate::__operator!($($value)*); };
+    (@ref_op != [$($value:tt)*] [$($borrowed:tt)*]) => { $crate::__operator!($($value)*); };
+    (@ref_op $op:tt [$($value:tt)*] [$($borrowed:tt)*]) => {
+        $crate::__operator!(@missing_output $op
+            compile_error!(concat!("The `", stringify!($op), "` operator is not supported with `ref`")););
+    };
+    (@ref_both [$($value:tt)*] [$($borrowed:tt)*]) => {
+        $crate::__operator!($($value)*);
+        $crate::__operator!($($borrowed)*);
     };
 
+    (@self_output + $($rest:tt)*) => { $crate::__operator!(@self_output_binary + $($rest)*); };
+    (@self_output - $($rest:tt)*) => { $crate::__operator!(@self_output_binary - $($rest)*); };
+    (@self_output * $($rest:tt)*) => { $crate::__operator!(@self_output_binary * $($rest)*); };
+    (@self_output / $($rest:tt)*) => { $crate::__operator!(@self_output_binary / $($rest)*); };
+    (@self_output % $($rest:tt)*) => { $crate::__operator!(@self_output_binary % $($rest)*); };
+    (@self_output & $($rest:tt)*) => { $crate::__operator!(@self_output_binary & $($rest)*); };
+    (@self_output | $($rest:tt)*) => { $crate::__operator!(@self_output_binary | $($rest)*); };
+    (@self_output ^ $($rest:tt)*) => { $crate::__operator!(@self_output_binary ^ $($rest)*); };
+    (@self_output << $($rest:tt)*) => { $crate::__operator!(@self_output_binary << $($rest)*); };
+    (@self_output >> $($rest:tt)*) => { $crate::__operator!(@self_output_binary >> $($rest)*); };
     (
-        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
-        $(#[$fn_attr:meta])* $a:ident << $b:ident -> $C:ty
-        { $($body:tt)* }
+        @self_output $op:tt [$O:ty] [$($prefix:tt)*] [$($operand:tt)*] $b:ident
+        $(where { $($where:tt)* })? { $($body:tt)* } $($rest:tt)*
     ) => {
-        $(#[$impl_attr])*
-        impl $(< $($generics)* >)? ::core::ops::Shl<$B> for $A {
-            type Output = $C;
+        $crate::__operator!($($prefix)* : $($operand)* $op $b
+            $(where { $($where)* })? { $($body)* } $($rest)*);
+    };
+    (
+        @self_output_binary $op:tt [$O:ty] [$($prefix:tt)*] [$($operand:tt)*] $b:ident
+        $(where { $($where:tt)* })? { $($body:tt)* } $($rest:tt)*
+    ) => {
+        $crate::__operator!($($prefix)* : $($operand)* $op $b -> $O
+            $(where { $($where)* })? { $($body)* } $($rest)*);
+    };
 
-            $(#[$fn_attr])*
-                fn shl(self, $b: $B) -> Self::Output {
-                    let $a = self;
-                    $($body)*
-                }
+    (@assign_of + $($rest:tt)*) => { $crate::__operator!(@assign += $($rest)*); };
+    (@assign_of - $($rest:tt)*) => { $crate::__operator!(@assign -= $($rest)*); };
+    (@assign_of * $($rest:tt)*) => { $crate::__operator!(@assign *= $($rest)*); };
+    (@assign_of / $($rest:tt)*) => { $crate::__operator!(@assign /= $($rest)*); };
+    (@assign_of % $($rest:tt)*) => { $crate::__operator!(@assign %= $($rest)*); };
+    (@assign_of & $($rest:tt)*) => { $crate::__operator!(@assign &= $($rest)*); };
+    (@assign_of | $($rest:tt)*) => { $crate::__operator!(@assign |= $($rest)*); };
+    (@assign_of ^ $($rest:tt)*) => { $crate::__operator!(@assign ^= $($rest)*); };
+    (@assign_of << $($rest:tt)*) => { $crate::__operator!(@assign <<= $($rest)*); };
+    (@assign_of >> $($rest:tt)*) => { $crate::__operator!(@assign >>= $($rest)*); };
+
+    (@assign += $($rest:tt)*) => { $crate::__operator!(@assign_impl AddAssign add_assign Add add $($rest)*); };
+    (@assign -= $($rest:tt)*) => { $crate::__operator!(@assign_impl SubAssign sub_assign Sub sub $($rest)*); };
+    (@assign *= $($rest:tt)*) => { $crate::__operator!(@assign_impl MulAssign mul_assign Mul mul $($rest)*); };
+    (@assign /= $($rest:tt)*) => { $crate::__operator!(@assign_impl DivAssign div_assign Div div $($rest)*); };
+    (@assign %= $($rest:tt)*) => { $crate::__operator!(@assign_impl RemAssign rem_assign Rem rem $($rest)*); };
+    (@assign &= $($rest:tt)*) => { $crate::__operator!(@assign_impl BitAndAssign bitand_assign BitAnd bitand $($rest)*); };
+    (@assign |= $($rest:tt)*) => { $crate::__operator!(@assign_impl BitOrAssign bitor_assign BitOr bitor $($rest)*); };
+    (@assign ^= $($rest:tt)*) => { $crate::__operator!(@assign_impl BitXorAssign bitxor_assign BitXor bitxor $($rest)*); };
+    (@assign <<= $($rest:tt)*) => { $crate::__operator!(@assign_impl ShlAssign shl_assign Shl shl $($rest)*); };
+    (@assign >>= $($rest:tt)*) => { $crate::__operator!(@assign_impl ShrAssign shr_assign Shr shr $($rest)*); };
+
+    (
+        @assign_impl $Assign:ident $assign:ident $Op:ident $op:ident [$($fn_attr:tt)*]
+        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty
+        $(where { $($where:tt)* })?
+    ) => {
+        $(#[$impl_attr])*
+        impl $(< $($generics)* >)? ::core::ops::$Assign<$B> for $A
+        where $A: ::core::clone::Clone + ::core::ops::$Op<$B, Output = $A>, $($($where)*)? {
+            $($fn_attr)*
+            fn $assign(&mut self, rhs: $B) {
+                *self = ::core::ops::$Op::$op(::core::clone::Clone::clone(self), rhs);
+            }
         }
     };
 
+    (@missing_output + $($fallback:tt)*) => { $crate::__operator!(@missing_output_error +); };
+    (@missing_output - $($fallback:tt)*) => { $crate::__operator!(@missing_output_error -); };
+    (@missing_output * $($fallback:tt)*) => { $crate::__operator!(@missing_output_error *); };
+    (@missing_output / $($fallback:tt)*) => { $crate::__operator!(@missing_output_error /); };
+    (@missing_output % $($fallback:tt)*) => { $crate::__operator!(@missing_output_error %); };
+    (@missing_output & $($fallback:tt)*) => { $crate::__operator!(@missing_output_error &); };
+    (@missing_output | $($fallback:tt)*) => { $crate::__operator!(@missing_output_error |); };
+    (@missing_output ^ $($fallback:tt)*) => { $crate::__operator!(@missing_output_error ^); };
+    (@missing_output << $($fallback:tt)*) => { $crate::__operator!(@missing_output_error <<); };
+    (@missing_output >> $($fallback:tt)*) => { $crate::__operator!(@missing_output_error >>); };
+    (@missing_output $op:tt $($fallback:tt)*) => { $($fallback)* };
+    (@missing_output_error $op:tt) => {
+        compile_error!(concat!(
+            "The `", stringify!($op), "` operator requires an output type, as in `a ",
+            stringify!($op), " b -> Output`"
+        ));
+    };
+
     (
-        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
-        $(#[$fn_attr:meta])* $a:ident >> $b:ident -> $C:ty
-        { $($body:tt)* }
+        $(@[$constness:ident])? $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* $a:ident $op:tt $b:ident
+        $(where { $($where:tt)* })?
+        { $($body:tt)* } $($rest:tt)*
     ) => {
-        $(#[$impl_attr])*
-        impl $(< $($generics)* >)? ::core::ops::Shr<$B> for $A {
-            type Output = $C;
+        $crate::__operator!(@self_output $op [$A]
+            [$(@[$constness])? $(#[$impl_attr])* $({ $($generics)* })? $A, $A]
+            [$(#[$fn_attr])* $a] $b $(where { $($where)* })? { $($body)* } $($rest)*);
+    };
 
-            $(#[$fn_attr])*
-                fn shr(self, $b: $B) -> Self::Output {
-                    let $a = self;
-                    $($body)*
-                }
-        }
+    (
+        $(@[$constness:ident])? $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* $a:ident $op:tt $b:ident where $($rest:tt)*
+    ) => {
+        $crate::__operator!(@where []
+            $(@[$constness])? $(#[$impl_attr])* $({ $($generics)* })? $A :
+            $(#[$fn_attr])* $a $op $b where $($rest)*);
     };
 
     (
-        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty, $B:ty :
-        $(#[$fn_attr:meta])* $a:ident[$b:ident] -> & $C:ty
-        { $($body:tt)* }
+        $(@[$constness:ident])? $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* $a:ident $op:tt $b:ident => $($rest:tt)*
     ) => {
-        $(#[$impl_attr])*
-        impl $(< $($generics)* >)? ::core::ops::Index<$B> for $A {
-            type Output = $C;
+        $crate::__operator!(@where []
+            $(@[$constness])? $(#[$impl_attr])* $({ $($generics)* })? $A :
+            $(#[$fn_attr])* $a $op $b => $($rest)*);
+    };
 
-            $(#[$fn_attr])*
-                fn index(&self, $b: $B) -> &Self::Output {
-                    let $a = self;
-                    $($body)*
-                }
-        }
+    (
+        $(@[$constness:ident])? $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* $a:ident $op:tt $b:ident $($rest:tt)*
+    ) => {
+        $crate::__operator!(
+            $(@[$constness])? $(#[$impl_attr])* $({ $($generics)* })? $A, $A :
+            $(#[$fn_attr])* $a $op $b $($rest)*);
     };
 
     (
         $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
-        $(#[$fn_attr:meta])* -$a:ident -> $C:ty
-        { $($body:tt)* }
+        $(#[$fn_attr:meta])* $a:ident <=> $b:ident $($rest:tt)*
     ) => {
-        $(#[$impl_attr])*
-        impl $(< $($generics)* >)? ::core::ops::Neg for $A {
-            type Output = $C;
+        $crate::__operator!(
+            $(#[$impl_attr])* $({ $($generics)* })? $A, $A :
+            $(#[$fn_attr])* $a <=> $b $($rest)*);
+    };
 
-            $(#[$fn_attr])*
-            fn neg(self) -> Self::Output {
-                let $a = self;
-                $($body)*
-            }
-        }
+    (
+        eq $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* $a:ident == $b:ident $($rest:tt)*
+    ) => {
+        $crate::__operator!(
+            eq $(#[$impl_attr])* $({ $($generics)* })? $A, $A :
+            $(#[$fn_attr])* $a == $b $($rest)*);
     };
 
     (
-        $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
-        $(#[$fn_attr:meta])* !$a:ident -> $C:ty
-        { $($body:tt)* }
+        ref $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* $a:ident $op:tt $b:ident
+        $(where { $($where:tt)* })?
+        { $($body:tt)* } $($rest:tt)*
     ) => {
-        $(#[$impl_attr])*
-        impl $(< $($generics)* >)? ::core::ops::Not for $A {
-            type Output = $C;
+        $crate::__operator!(@self_output $op [$A]
+            [ref $(#[$impl_attr])* $({ $($generics)* })? $A, $A]
+            [$(#[$fn_attr])* $a] $b $(where { $($where)* })? { $($body)* } $($rest)*);
+    };
 
-            $(#[$fn_attr])*
-            fn not(self) -> Self::Output {
-                let $a = self;
-                $($body)*
-            }
-        }
+    (
+        ref $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* $a:ident $op:tt $b:ident where $($rest:tt)*
+    ) => {
+        $crate::__operator!(@where []
+            ref $(#[$impl_attr])* $({ $($generics)* })? $A :
+            $(#[$fn_attr])* $a $op $b where $($rest)*);
+    };
+
+    (
+        ref $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* $a:ident $op:tt $b:ident => $($rest:tt)*
+    ) => {
+        $crate::__operator!(@where []
+            ref $(#[$impl_attr])* $({ $($generics)* })? $A :
+            $(#[$fn_attr])* $a $op $b => $($rest)*);
+    };
+
+    (
+        ref $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* $a:ident $op:tt $b:ident $($rest:tt)*
+    ) => {
+        $crate::__operator!(
+            ref $(#[$impl_attr])* $({ $($generics)* })? $A, $A :
+            $(#[$fn_attr])* $a $op $b $($rest)*);
+    };
+
+    (
+        ref_clone $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* $a:ident $op:tt $b:ident
+        $(where { $($where:tt)* })?
+        { $($body:tt)* } $($rest:tt)*
+    ) => {
+        $crate::__operator!(@self_output $op [$A]
+            [ref_clone $(#[$impl_attr])* $({ $($generics)* })? $A, $A]
+            [$(#[$fn_attr])* $a] $b $(where { $($where)* })? { $($body)* } $($rest)*);
+    };
+
+    (
+        ref_clone $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* $a:ident $op:tt $b:ident where $($rest:tt)*
+    ) => {
+        $crate::__operator!(@where []
+            ref_clone $(#[$impl_attr])* $({ $($generics)* })? $A :
+            $(#[$fn_attr])* $a $op $b where $($rest)*);
+    };
+
+    (
+        ref_clone $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* $a:ident $op:tt $b:ident => $($rest:tt)*
+    ) => {
+        $crate::__operator!(@where []
+            ref_clone $(#[$impl_attr])* $({ $($generics)* })? $A :
+            $(#[$fn_attr])* $a $op $b => $($rest)*);
+    };
+
+    (
+        ref_clone $(#[$impl_attr:meta])* $({ $($generics:tt)* })? $A:ty :
+        $(#[$fn_attr:meta])* $a:ident $op:tt $b:ident $($rest:tt)*
+    ) => {
+        $crate::__operator!(
+            ref_clone $(#[$impl_attr])* $({ $($generics)* })? $A, $A :
+            $(#[$fn_attr])* $a $op $b $($rest)*);
+    };
+
+    (@where [$($pre:tt)*] where { $($where:tt)* } $($rest:tt)*) => {
+        $crate::__operator!(@where [$($pre)* where { $($where)* }] $($rest)*);
+    };
+    (@where [$($pre:tt)*] where $($rest:tt)*) => {
+        $crate::__operator!(@where_clause [$($pre)*] [] $($rest)*);
+    };
+    (@where [$($pre:tt)*] => $body:expr) => {
+        $crate::__operator!($($pre)* { $body });
+    };
+    (@where [$($pre:tt)*] $token:tt $($rest:tt)*) => {
+        $crate::__operator!(@where [$($pre)* $token] $($rest)*);
+    };
+    (@where [$($pre:tt)*]) => {
+        $crate::__operator!(@pat [] [] $($pre)*);
+    };
+
+    (@where_clause [$($pre:tt)*] [$($where:tt)*] { $($body:tt)* } $($rest:tt)*) => {
+        $crate::__operator!($($pre)* where { $($where)* } { $($body)* } $($rest)*);
+    };
+    (@where_clause [$($pre:tt)*] [$($where:tt)*] => $body:expr) => {
+        $crate::__operator!($($pre)* where { $($where)* } { $body });
+    };
+    (@where_clause [$($pre:tt)*] [$($where:tt)*] $token:tt $($rest:tt)*) => {
+        $crate::__operator!(@where_clause [$($pre)*] [$($where)* $token] $($rest)*);
+    };
+
+    (@pat [$($pre:tt)*] [] : $($rest:tt)*) => {
+        $crate::__operator!(@pat_operands [$($pre)* :] $($rest)*);
+    };
+    (@pat [$($pre:tt)*] [$($depth:tt)*] < $($rest:tt)*) => {
+        $crate::__operator!(@pat [$($pre)* <] [@ $($depth)*] $($rest)*);
+    };
+    (@pat [$($pre:tt)*] [$($depth:tt)*] << $($rest:tt)*) => {
+        $crate::__operator!(@pat [$($pre)* <<] [@ @ $($depth)*] $($rest)*);
+    };
+    (@pat [$($pre:tt)*] [@ $($depth:tt)*] > $($rest:tt)*) => {
+        $crate::__operator!(@pat [$($pre)* >] [$($depth)*] $($rest)*);
+    };
+    (@pat [$($pre:tt)*] [@ @ $($depth:tt)*] >> $($rest:tt)*) => {
+        $crate::__operator!(@pat [$($pre)* >>] [$($depth)*] $($rest)*);
+    };
+    (@pat [$($pre:tt)*] [$($depth:tt)*] $token:tt $($rest:tt)*) => {
+        $crate::__operator!(@pat [$($pre)* $token] [$($depth)*] $($rest)*);
+    };
+    (@pat [$($pre:tt)*] [$($depth:tt)*]) => {
+        compile_error!("Unrecognized operator syntax");
+    };
+
+    (@pat_operands [$($pre:tt)*] #[$($attr:tt)*] $($rest:tt)*) => {
+        $crate::__operator!(@pat_operands [$($pre)* #[$($attr)*]] $($rest)*);
+    };
+    (@pat_operands [$($pre:tt)*] $a:ident ($($a_pat:tt)*) -> $($rest:tt)*) => {
+        compile_error!("Unrecognized operator syntax");
+    };
+    (@pat_operands [$($pre:tt)*] $a:ident ($($a_pat:tt)*) $($rest:tt)*) => {
+        $crate::__operator!(@pat_rhs [$($pre)* lhs] [let $a($($a_pat)*) = lhs;] $($rest)*);
+    };
+    (@pat_operands [$($pre:tt)*] $a:ident { $($a_pat:tt)* } $($rest:tt)*) => {
+        $crate::__operator!(@pat_rhs [$($pre)* lhs] [let $a { $($a_pat)* } = lhs;] $($rest)*);
+    };
+    (@pat_operands [$($pre:tt)*] - $a:ident ($($a_pat:tt)*) $($rest:tt)*) => {
+        $crate::__operator!(@pat_body [$($pre)* - lhs] [let $a($($a_pat)*) = lhs;] $($rest)*);
+    };
+    (@pat_operands [$($pre:tt)*] - $a:ident { $($a_pat:tt)* } $($rest:tt)*) => {
+        $crate::__operator!(@pat_body [$($pre)* - lhs] [let $a { $($a_pat)* } = lhs;] $($rest)*);
+    };
+    (@pat_operands [$($pre:tt)*] ! $a:ident ($($a_pat:tt)*) $($rest:tt)*) => {
+        $crate::__operator!(@pat_body [$($pre)* ! lhs] [let $a($($a_pat)*) = lhs;] $($rest)*);
+    };
+    (@pat_operands [$($pre:tt)*] ! $a:ident { $($a_pat:tt)* } $($rest:tt)*) => {
+        $crate::__operator!(@pat_body [$($pre)* ! lhs] [let $a { $($a_pat)* } = lhs;] $($rest)*);
+    };
+    (@pat_operands [$($pre:tt)*] $a:ident $($rest:tt)*) => {
+        $crate::__operator!(@pat_rhs [$($pre)* $a] [] $($rest)*);
+    };
+    (@pat_operands [$($pre:tt)*] $($rest:tt)*) => {
+        $crate::__operator!(@pat_unsupported $($rest)*);
+    };
+
+    (@pat_rhs [$($pre:tt)*] [$($lets:tt)*] $op:tt $b:ident ($($b_pat:tt)*) $($rest:tt)*) => {
+        $crate::__operator!(@pat_body [$($pre)* $op rhs] [$($lets)* let $b($($b_pat)*) = rhs;] $($rest)*);
+    };
+    (@pat_rhs [$($pre:tt)*] [$($lets:tt)*] $op:tt $b:ident { $($b_pat:tt)* } $next:tt $($rest:tt)*) => {
+        $crate::__operator!(@pat_body [$($pre)* $op rhs] [$($lets)* let $b { $($b_pat)* } = rhs;] $next $($rest)*);
+    };
+    (@pat_rhs [$($pre:tt)*] [$($lets:tt)+] $op:tt $b:ident $($rest:tt)*) => {
+        $crate::__operator!(@pat_body [$($pre)* $op $b] [$($lets)+] $($rest)*);
+    };
+    (@pat_rhs [$($pre:tt)*] [$($lets:tt)*] $op:tt $($rest:tt)*) => {
+        $crate::__operator!(@pat_unsupported $($rest)*);
+    };
+    (@pat_rhs [$($pre:tt)*] [$($lets:tt)*]) => {
+        compile_error!("Unrecognized operator syntax");
+    };
+
+    (@pat_unsupported & $($rest:tt)*) => { $crate::__operator!(@pat_unsupported_error); };
+    (@pat_unsupported ($($pat:tt)*) $($rest:tt)*) => { $crate::__operator!(@pat_unsupported_error); };
+    (@pat_unsupported [$($pat:tt)*] $($rest:tt)*) => { $crate::__operator!(@pat_unsupported_error); };
+    (@pat_unsupported $($rest:tt)*) => {
+        compile_error!("Unrecognized operator syntax");
+    };
+    (@pat_unsupported_error) => {
+        compile_error!("Only tuple struct and struct patterns, as in `Point(x, y)` or `Point { x, y }`, can be used in place of an operand name");
+    };
+
+    (@pat_body [$($pre:tt)*] [$($lets:tt)*] where { $($where:tt)* } $($rest:tt)*) => {
+        $crate::__operator!(@pat_body [$($pre)* where { $($where)* }] [$($lets)*] $($rest)*);
+    };
+    (@pat_body [$($pre:tt)*] [$($lets:tt)*] { $($body:tt)* } $($rest:tt)*) => {
+        $crate::__operator!($($pre)* { $($lets)* $($body)* } $($rest)*);
+    };
+    (@pat_body [$($pre:tt)*] [$($lets:tt)*] $token:tt $($rest:tt)*) => {
+        $crate::__operator!(@pat_body [$($pre)* $token] [$($lets)*] $($rest)*);
+    };
+    (@pat_body [$($pre:tt)*] [$($lets:tt)*]) => {
+        compile_error!("Unrecognized operator syntax");
+    };
+
+    ($($tokens:tt)*) => {
+        $crate::__operator!(@where [] $($tokens)*);
+    };
+}
+
+#[macro_export]
+macro_rules! operators {
+    (@items [$($header:tt)*]) => {};
+    (@items [$($header:tt)*] , $($rest:tt)*) => {
+        $crate::operators!(@items [$($header)*] $($rest)*);
+    };
+    (@items [$($header:tt)*] ; $($rest:tt)*) => {
+        $crate::operators!(@items [$($header)*] $($rest)*);
+    };
+    (@items [$($header:tt)*] $($rest:tt)+) => {
+        $crate::operators!(@item [$($header)*] [] $($rest)+);
+    };
+
+    (@item [$($header:tt)*] [$($item:tt)*] => $body:expr) => {
+        $crate::__operator!($($header)* : $($item)* => $body);
+    };
+    (@item [$($header:tt)*] [$($item:tt)*] => $body:expr , $($rest:tt)*) => {
+        $crate::__operator!($($header)* : $($item)* => $body);
+        $crate::operators!(@items [$($header)*] $($rest)*);
+    };
+    (@item [$($header:tt)*] [$($item:tt)*] => $body:expr ; $($rest:tt)*) => {
+        $crate::__operator!($($header)* : $($item)* => $body);
+        $crate::operators!(@items [$($header)*] $($rest)*);
+    };
+    (@item [$($header:tt)*] [$($item:tt)*] { $($body:tt)* } with assign $($rest:tt)*) => {
+        $crate::__operator!($($header)* : $($item)* { $($body)* } with assign);
+        $crate::operators!(@items [$($header)*] $($rest)*);
+    };
+    (@item [$($header:tt)*] [$($item:tt)*] { $($body:tt)* } mut { $($body_mut:tt)* } $($rest:tt)*) => {
+        $crate::__operator!($($header)* : $($item)* { $($body)* } mut { $($body_mut)* });
+        $crate::operators!(@items [$($header)*] $($rest)*);
+    };
+    (
+        @item [$($header:tt)*] [$($item:tt)*] { $($body:tt)* }
+        $(#[$ne_attr:meta])* $c:ident != $d:ident $(-> bool)? { $($ne_body:tt)* } $($rest:tt)*
+    ) => {
+        $crate::__operator!($($header)* : $($item)* { $($body)* }
+            $(#[$ne_attr])* $c != $d { $($ne_body)* });
+        $crate::operators!(@items [$($header)*] $($rest)*);
+    };
+    (
+        @item [$($header:tt)*] [$($item:tt)*] { $($body:tt)* }
+        + pub $(($($restrict:tt)*))? $flag:ident $($rest:tt)*
+    ) => {
+        $crate::operators!(@flags [$($header)*] [$($item)* { $($body)* } + pub $(($($restrict)*))? $flag] $($rest)*);
+    };
+    (@item [$($header:tt)*] [$($item:tt)*] { $($body:tt)* } + $flag:ident $($rest:tt)*) => {
+        $crate::operators!(@flags [$($header)*] [$($item)* { $($body)* } + $flag] $($rest)*);
+    };
+    (@item [$($header:tt)*] [$($item:tt)*] { $($body:tt)* } $($rest:tt)*) => {
+        $crate::__operator!($($header)* : $($item)* { $($body)* });
+        $crate::operators!(@items [$($header)*] $($rest)*);
+    };
+    (@item [$($header:tt)*] [$($item:tt)*] $token:tt $($rest:tt)*) => {
+        $crate::operators!(@item [$($header)*] [$($item)* $token] $($rest)*);
+    };
+
+    (@flags [$($header:tt)*] [$($item:tt)*] + pub $(($($restrict:tt)*))? $flag:ident $($rest:tt)*) => {
+        $crate::operators!(@flags [$($header)*] [$($item)* + pub $(($($restrict)*))? $flag] $($rest)*);
+    };
+    (@flags [$($header:tt)*] [$($item:tt)*] + $flag:ident $($rest:tt)*) => {
+        $crate::operators!(@flags [$($header)*] [$($item)* + $flag] $($rest)*);
+    };
+    (@flags [$($header:tt)*] [$($item:tt)*] $($rest:tt)*) => {
+        $crate::__operator!($($header)* : $($item)*);
+        $crate::operators!(@items [$($header)*] $($rest)*);
+    };
+
+    (@header [$($header:tt)*] { $($items:tt)* }) => {
+        $crate::operators!(@items [$($header)*] $($items)*);
+    };
+    (@header [$($header:tt)*] $token:tt $($rest:tt)+) => {
+        $crate::operators!(@header [$($header)* $token] $($rest)+);
+    };
+
+    ($($tokens:tt)*) => {
+        $crate::operators!(@header [] $($tokens)*);
+    };
+}
+
+#[cfg(feature = "fn_traits")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __operator_fn_traits {
+    ($($tt:tt)*) => { $($tt)* };
+}
+
+#[cfg(not(feature = "fn_traits"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __operator_fn_traits {
+    ($($tt:tt)*) => {
+        compile_error!("The call operator requires the `fn_traits` feature of operator-sugar");
+    };
+}
+
+#[cfg(feature = "const_ops")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __operator_const_ops {
+    ($($tt:tt)*) => { $($tt)* };
+}
+
+#[cfg(not(feature = "const_ops"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __operator_const_ops {
+    ($($tt:tt)*) => {
+        compile_error!("The `const` prefix requires the `const_ops` feature of operator-sugar");
+    };
+}
+
+#[cfg(feature = "num-traits")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __operator_num_traits {
+    ($($tt:tt)*) => { $($tt)* };
+}
+
+#[cfg(not(feature = "num-traits"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __operator_num_traits {
+    ($($tt:tt)*) => {
+        compile_error!("The `num` prefix requires the `num-traits` feature of operator-sugar");
     };
 }
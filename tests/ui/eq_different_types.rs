@@ -0,0 +1,11 @@
+use operator_sugar::*;
+
+#[derive(PartialEq)]
+struct Name(String);
+struct Alias(String);
+
+operator!(eq Name, Alias: a == b {
+    a.0 == b.0
+});
+
+fn main() {}
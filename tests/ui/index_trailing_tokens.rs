@@ -0,0 +1,9 @@
+use operator_sugar::*;
+
+struct List(Vec<i32>);
+
+operator!(List, usize: a[b] -> &mut i32 {
+    &mut a.0[b]
+} extra);
+
+fn main() {}
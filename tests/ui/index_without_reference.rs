@@ -0,0 +1,10 @@
+use operator_sugar::*;
+
+struct Left(Vec<i32>);
+struct Right(usize);
+
+operator!(Left, Right: a[b] -> i32 {
+    a.0[b.0]
+});
+
+fn main() {}
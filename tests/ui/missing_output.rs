@@ -0,0 +1,10 @@
+use operator_sugar::*;
+
+struct Left(i32);
+struct Right(i32);
+
+operator!(Left, Right: a + b {
+    a.0 + b.0
+});
+
+fn main() {}
@@ -0,0 +1,7 @@
+use operator_sugar::*;
+
+struct Counter(u32);
+
+operator!(wrapping Counter: + <<);
+
+fn main() {}
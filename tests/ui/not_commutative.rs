@@ -0,0 +1,10 @@
+use operator_sugar::*;
+
+struct Meters(f64);
+struct Feet(f64);
+
+operator!(commutative Meters, Feet: a - b -> f64 {
+    a.0 - b.0 * 0.3048
+});
+
+fn main() {}
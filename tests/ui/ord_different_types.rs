@@ -0,0 +1,12 @@
+use core::cmp::Ordering;
+use operator_sugar::*;
+
+#[derive(PartialEq, Eq, PartialOrd)]
+struct Task(u32);
+struct Priority(u32);
+
+operator!(Task, Priority: a <=> b -> Ordering {
+    a.0.cmp(&b.0)
+});
+
+fn main() {}
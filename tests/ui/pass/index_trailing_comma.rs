@@ -0,0 +1,25 @@
+use operator_sugar::*;
+
+struct List(Vec<i32>);
+struct Grid(Vec<i32>);
+
+operator!(List, usize: a[b] -> &i32 {
+    &a.0[b]
+},);
+
+operator!(Grid, usize: a[b] -> &i32 {
+    &a.0[b]
+} mut {
+    &mut a.0[b]
+},);
+
+operator!(Grid, u32: a[b] -> &i32 { &a.0[b as usize] });
+operator!(Grid, u32: a[b] -> &mut i32 { &mut a.0[b as usize] },);
+
+fn main() {
+    let mut grid = Grid(vec![1, 2]);
+    grid[0usize] += 1;
+    grid[1u32] += 1;
+    assert_eq!(List(vec![3])[0], 3);
+    assert_eq!((grid[0usize], grid[1u32]), (2, 3));
+}
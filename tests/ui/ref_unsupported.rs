@@ -0,0 +1,10 @@
+use operator_sugar::*;
+
+#[derive(Clone, Copy, PartialEq)]
+struct Version(u32);
+
+operator!(ref Version: a < b {
+    a.0 < b.0
+});
+
+fn main() {}
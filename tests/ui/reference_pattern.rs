@@ -0,0 +1,9 @@
+use operator_sugar::*;
+
+struct Point(i32, i32);
+
+operator!(Point, i32: Point(x, y) * &k -> Point {
+    Point(x * k, y * k)
+});
+
+fn main() {}
@@ -0,0 +1,9 @@
+use operator_sugar::*;
+
+struct Version(u32, u32);
+
+operator!(by_key Version: self <=> other {
+    (self.0, self.1)
+});
+
+fn main() {}
@@ -0,0 +1,10 @@
+use operator_sugar::*;
+
+struct Meters(f64);
+struct Feet(f64);
+
+operator!(swap Meters, Feet: self - rhs -> f64 {
+    self.0 - rhs.0 * 0.3048
+});
+
+fn main() {}
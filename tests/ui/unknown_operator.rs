@@ -0,0 +1,10 @@
+use operator_sugar::*;
+
+struct Left(bool);
+struct Right(bool);
+
+operator!(Left, Right: a && b -> bool {
+    a.0 && b.0
+});
+
+fn main() {}
@@ -0,0 +1,11 @@
+use operator_sugar::*;
+use std::cmp::Ordering;
+
+#[derive(PartialEq, Eq, PartialOrd)]
+struct Task(u32);
+
+operator!(Task: a <=> b -> Ordering {
+    a.0.cmp(&b.0)
+} + partial);
+
+fn main() {}